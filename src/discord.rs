@@ -3,24 +3,37 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 mod error;
+pub mod paginate;
+mod query;
 pub mod requests;
 
 use crate::image;
+use crate::resources::channel::{Channel, ChannelId, Message, MessageId};
+use crate::resources::user::UserId;
 use crate::str::obscure;
 
 use educe::Educe;
 
-use reqwest::header::{self, HeaderMap, HeaderValue};
-use reqwest::{ClientBuilder, Response, Url};
+use reqwest::header::{self, HeaderValue};
+use reqwest::{
+    multipart, ClientBuilder, Method, RequestBuilder, Response, StatusCode, Url,
+};
 
-pub use self::error::Error;
+pub use self::error::{Error, FieldError};
 
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 use snafu::ResultExt;
 
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::OnceCell;
+use tokio::time::Instant;
 
 use typed_builder::TypedBuilder;
 
@@ -65,7 +78,17 @@ impl Token {
     }
 }
 
-#[derive(Debug, TypedBuilder)] // TODO: impl Deserialize
+/// Customizes the `reqwest::ClientBuilder` used by [`Discord`].
+pub type ClientBuilderHook =
+    Box<dyn Fn(ClientBuilder) -> ClientBuilder + Send + Sync>;
+
+/// Observes every response from Discord, given the request method and path,
+/// and the response status and body.
+pub type ResponseHook =
+    Arc<dyn Fn(&Method, &str, StatusCode, &str) + Send + Sync>;
+
+#[derive(Educe, TypedBuilder)] // TODO: impl Deserialize
+#[educe(Debug)]
 #[builder(doc)]
 pub struct Config {
     token: Token,
@@ -79,37 +102,152 @@ pub struct Config {
     #[builder(default_code = "Config::DEFAULT_VERSION.to_owned()")]
     version: String,
 
-    #[builder(default_code = "Config::DEFAULT_API_ROOT.to_owned()")]
-    api_root: String,
+    /// The version of Discord's API to use, unless `api_root` is set.
+    #[builder(default = Config::DEFAULT_API_VERSION)]
+    api_version: u8,
+
+    /// Overrides the API root built from `api_version`, for example to go
+    /// through a proxy.
+    #[builder(default, setter(strip_option))]
+    api_root: Option<String>,
 
     #[builder(default_code = "Config::DEFAULT_CDN_ROOT.to_owned()")]
     cdn_root: String,
+
+    /// Total time allowed for each request, from connecting until the
+    /// response body has been read.
+    #[builder(default, setter(strip_option))]
+    timeout: Option<Duration>,
+
+    /// Called with the `ClientBuilder` just before the HTTP client is built,
+    /// to configure proxies, TLS, and so on.
+    #[builder(default, setter(strip_option))]
+    #[educe(Debug(ignore))]
+    client_builder: Option<ClientBuilderHook>,
+
+    /// Called with each response, to log or trace raw bodies while
+    /// debugging. Request headers, including the token, are never passed to
+    /// the hook. Bodies from the OAuth2 token endpoint are redacted, as are
+    /// tokens in other bodies and in webhook paths.
+    #[builder(default, setter(strip_option))]
+    #[educe(Debug(ignore))]
+    response_hook: Option<ResponseHook>,
 }
 
 impl Config {
     const DEFAULT_NAME: &'static str = "RustDiscord2Bot";
     const DEFAULT_URL: &'static str = env!("CARGO_PKG_REPOSITORY");
     const DEFAULT_VERSION: &'static str = env!("CARGO_PKG_VERSION");
-    const DEFAULT_API_ROOT: &'static str = "https://discord.com/api/v9/";
+    const DEFAULT_API_VERSION: u8 = 10;
     const DEFAULT_CDN_ROOT: &'static str = "https://cdn.discordapp.com/";
+
+    fn api_root(&self) -> String {
+        match &self.api_root {
+            Some(root) => root.clone(),
+            None => format!("https://discord.com/api/v{}/", self.api_version),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
 struct DiscordError {
     code: Option<u64>,
     message: Option<String>,
+    errors: Option<serde_json::Value>,
 }
 
-#[derive(Debug)]
+/// How many times a request is retried after hitting the global rate limit,
+/// before the `429 Too Many Requests` response is returned instead.
+const MAX_GLOBAL_RETRIES: usize = 3;
+
+/// Hide the token in paths like `webhooks/{id}/{token}/...` before they're
+/// shown to a [`ResponseHook`].
+fn redact_path(path: &str) -> String {
+    let mut segments: Vec<&str> = path.split('/').collect();
+
+    if let Some(idx) = segments.iter().position(|s| *s == "webhooks") {
+        if let Some(token) = segments.get_mut(idx + 2) {
+            *token = "[redacted]";
+        }
+    }
+
+    segments.join("/")
+}
+
+/// Hide the value of every `token`, `access_token`, and `refresh_token` in
+/// `value`, however deeply nested.
+///
+/// Returns whether anything was hidden.
+fn redact_tokens(value: &mut serde_json::Value) -> bool {
+    const SECRETS: &[&str] = &["token", "access_token", "refresh_token"];
+
+    let mut redacted = false;
+
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                if SECRETS.contains(&key.as_str()) && !child.is_null() {
+                    *child = "[redacted]".into();
+                    redacted = true;
+                } else {
+                    redacted |= redact_tokens(child);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                redacted |= redact_tokens(item);
+            }
+        }
+        _ => (),
+    }
+
+    redacted
+}
+
+/// Hide any tokens in a response `body` before it's shown to a
+/// [`ResponseHook`]. Bodies that aren't JSON are shown unchanged.
+fn redact_body(body: &str) -> Cow<'_, str> {
+    let mut value: serde_json::Value = match serde_json::from_str(body) {
+        Ok(value) => value,
+        Err(_) => return Cow::Borrowed(body),
+    };
+
+    if redact_tokens(&mut value) {
+        Cow::Owned(value.to_string())
+    } else {
+        Cow::Borrowed(body)
+    }
+}
+
+/// A client for Discord's HTTP API.
+///
+/// Cloning a `Discord` is cheap, and clones share the same underlying
+/// connection pool, so a clone can be moved into each spawned task instead of
+/// wrapping the client in an `Arc`.
+#[derive(Educe, Clone)]
+#[educe(Debug)]
 pub struct Discord {
     cdn_root: Url,
     api_root: Url,
     client: reqwest::Client,
+    #[educe(Debug(ignore))]
+    authorization: HeaderValue,
+    #[educe(Debug(ignore))]
+    response_hook: Option<ResponseHook>,
+    #[educe(Debug(ignore))]
+    dm_channels: Arc<Mutex<HashMap<UserId, ChannelId>>>,
+    #[educe(Debug(ignore))]
+    current_user_id: Arc<OnceCell<UserId>>,
+    /// When a global rate limit ends, shared so that every clone waits it
+    /// out together.
+    #[educe(Debug(ignore))]
+    global_reset: Arc<Mutex<Option<Instant>>>,
 }
 
 impl Discord {
     pub fn new(config: &Config) -> Result<Self, Error> {
-        let api_root = Url::from_str(&config.api_root)
+        let api_root = Url::from_str(&config.api_root())
             .map_err(|e| Box::new(e) as Box<_>)
             .context(error::InvalidConfig)?;
 
@@ -117,25 +255,140 @@ impl Discord {
             .map_err(|e| Box::new(e) as Box<_>)
             .context(error::InvalidConfig)?;
 
-        let mut headers = HeaderMap::new();
-        headers.insert(header::AUTHORIZATION, config.token.to_header_value()?);
+        let authorization = config.token.to_header_value()?;
 
         let user_agent_txt =
             format!("{} ({}, {})", config.name, config.url, config.version,);
         let user_agent = HeaderValue::from_str(&user_agent_txt)?;
 
-        let client = ClientBuilder::new()
-            .default_headers(headers)
-            .user_agent(user_agent)
-            .build()?;
+        let mut builder = ClientBuilder::new().user_agent(user_agent);
+
+        if let Some(timeout) = config.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if let Some(hook) = &config.client_builder {
+            builder = hook(builder);
+        }
+
+        let client = builder.build()?;
 
         Ok(Self {
             cdn_root,
             api_root,
             client,
+            authorization,
+            response_hook: config.response_hook.clone(),
+            dm_channels: Default::default(),
+            current_user_id: Default::default(),
+            global_reset: Default::default(),
         })
     }
 
+    /// Send any [`Request`](requests::Request) to Discord.
+    pub async fn request<R>(&self, request: &R) -> Result<R::Output, Error>
+    where
+        R: requests::Request,
+    {
+        request.execute(self).await
+    }
+
+    /// Page backwards through a channel's message history, newest first.
+    pub fn messages(
+        &self,
+        channel_id: ChannelId,
+    ) -> paginate::Paginator<requests::GetChannelMessages> {
+        let request = requests::GetChannelMessages::builder()
+            .channel_id(channel_id)
+            .limit(100)
+            .build();
+
+        paginate::Paginator::new(self, request)
+    }
+
+    /// Page through every user who reacted to a message with `emoji`, given
+    /// in reaction form (see [`Emoji::reaction_form`]).
+    ///
+    /// [`Emoji::reaction_form`]: crate::resources::emoji::Emoji::reaction_form
+    pub fn reactions<S>(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: S,
+    ) -> paginate::Paginator<requests::GetReactions>
+    where
+        S: Into<String>,
+    {
+        let request = requests::GetReactions::builder()
+            .channel_id(channel_id)
+            .message_id(message_id)
+            .emoji(emoji)
+            .limit(100)
+            .build();
+
+        paginate::Paginator::new(self, request)
+    }
+
+    /// The id of the user this client is authenticated as.
+    ///
+    /// Only the first call, from this client or its clones, asks Discord;
+    /// calls made while it's in flight wait for it, and later calls return
+    /// the cached id. If asking fails, the next call tries again.
+    pub async fn current_user_id(&self) -> Result<UserId, Error> {
+        let id = self
+            .current_user_id
+            .get_or_try_init(|| async {
+                let request = requests::GetCurrentUser::builder().build();
+                request.send(self).await.map(|user| user.id())
+            })
+            .await?;
+
+        Ok(*id)
+    }
+
+    /// Open a DM channel with `user_id`, or get the one that's already open.
+    pub async fn dm(&self, user_id: UserId) -> Result<Channel, Error> {
+        let channel = requests::CreateDm::builder()
+            .recipient_id(user_id)
+            .build()
+            .send(self)
+            .await?;
+
+        self.dm_channels
+            .lock()
+            .unwrap()
+            .insert(user_id, channel.id());
+
+        Ok(channel)
+    }
+
+    /// Send `content` to `user_id` in a DM.
+    ///
+    /// The DM channel is only opened the first time a user is messaged; later
+    /// calls, from this client or its clones, reuse the channel id.
+    pub async fn send_dm<S>(
+        &self,
+        user_id: UserId,
+        content: S,
+    ) -> Result<Message, Error>
+    where
+        S: Into<String>,
+    {
+        let cached = self.dm_channels.lock().unwrap().get(&user_id).copied();
+
+        let channel_id = match cached {
+            Some(id) => id,
+            None => self.dm(user_id).await?.id(),
+        };
+
+        requests::CreateMessage::builder()
+            .channel_id(channel_id)
+            .content(content)
+            .build()
+            .send(self)
+            .await
+    }
+
     pub fn image_url<I>(
         &self,
         image: I,
@@ -156,24 +409,162 @@ impl Discord {
         self.api_root.join(path.as_ref()).unwrap()
     }
 
-    async fn handle_response<T>(&self, response: Response) -> Result<T, Error>
+    /// Start a request that carries the configured token.
+    ///
+    /// The token is added per request, rather than as a default header, so
+    /// that requests authenticated some other way can leave it out.
+    fn authorized(&self, method: Method, url: Url) -> RequestBuilder {
+        self.client
+            .request(method, url)
+            .header(header::AUTHORIZATION, self.authorization.clone())
+    }
+
+    /// How long to back off, if `response` is a global rate limit.
+    fn global_retry_after(response: &Response) -> Option<Duration> {
+        if response.status() != StatusCode::TOO_MANY_REQUESTS {
+            return None;
+        }
+
+        let headers = response.headers();
+
+        let global = headers
+            .get("x-ratelimit-global")
+            .and_then(|v| v.to_str().ok());
+
+        if global != Some("true") {
+            return None;
+        }
+
+        let seconds = headers
+            .get(header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<f64>().ok())?;
+
+        // Negative, infinite, and NaN delays are treated as though the
+        // header were missing.
+        Duration::try_from_secs_f64(seconds).ok()
+    }
+
+    async fn wait_for_global_reset(&self) {
+        loop {
+            let reset = *self.global_reset.lock().unwrap();
+
+            match reset {
+                Some(at) if at > Instant::now() => {
+                    tokio::time::sleep_until(at).await
+                }
+                _ => return,
+            }
+        }
+    }
+
+    /// Send `request`, first waiting out any global rate limit.
+    ///
+    /// A global rate limit response holds back every request from this
+    /// client and its clones until it resets, then the request is retried.
+    /// Requests that can't be cloned, like multipart uploads, aren't
+    /// retried, and the rate limit response is returned instead.
+    async fn send(
+        &self,
+        mut request: RequestBuilder,
+    ) -> Result<Response, Error> {
+        let mut retries = 0;
+
+        loop {
+            let retry = request.try_clone();
+
+            self.wait_for_global_reset().await;
+            let response = request.send().await?;
+
+            // A delay too long to represent is treated like a missing one.
+            let reset = Self::global_retry_after(&response)
+                .and_then(|delay| Instant::now().checked_add(delay));
+
+            let reset = match reset {
+                Some(reset) => reset,
+                None => return Ok(response),
+            };
+            {
+                let mut global = self.global_reset.lock().unwrap();
+                if !matches!(*global, Some(at) if at >= reset) {
+                    *global = Some(reset);
+                }
+            }
+
+            match retry {
+                Some(retry) if retries < MAX_GLOBAL_RETRIES => {
+                    retries += 1;
+                    request = retry;
+                }
+                _ => return Ok(response),
+            }
+        }
+    }
+
+    async fn read_body(
+        &self,
+        method: Method,
+        response: Response,
+    ) -> Result<(StatusCode, String), Error> {
+        let status = response.status();
+        let path = redact_path(response.url().path());
+        let body = response.text().await?;
+
+        if let Some(hook) = &self.response_hook {
+            let shown = if path.ends_with("oauth2/token") {
+                Cow::Borrowed("[redacted]")
+            } else {
+                redact_body(&body)
+            };
+
+            hook(&method, &path, status, &shown);
+        }
+
+        Ok((status, body))
+    }
+
+    async fn handle_response<T>(
+        &self,
+        method: Method,
+        response: Response,
+    ) -> Result<T, Error>
     where
         T: DeserializeOwned,
     {
-        if response.status().is_success() {
-            //let json: serde_json::Value = response.json().await?;
-            //eprintln!("json: {}", json);
-            //Ok(serde_json::from_value(json).unwrap())
-            Ok(response.json().await?)
+        let (status, body) = self.read_body(method, response).await?;
+
+        if status.is_success() {
+            serde_json::from_str(&body)
+                .context(error::Deserialize { body: &body })
         } else {
-            let err: DiscordError = response.json().await?;
+            Self::fail(body)
+        }
+    }
 
-            error::Discord {
-                code: err.code,
-                message: err.message,
-            }
-            .fail()
+    async fn handle_empty_response(
+        &self,
+        method: Method,
+        response: Response,
+    ) -> Result<(), Error> {
+        let (status, body) = self.read_body(method, response).await?;
+
+        if status.is_success() {
+            Ok(())
+        } else {
+            Self::fail(body)
+        }
+    }
+
+    fn fail<T>(body: String) -> Result<T, Error> {
+        let err: DiscordError = serde_json::from_str(&body)
+            .context(error::Deserialize { body: &body })?;
+
+        error::Discord {
+            code: err.code,
+            message: err.message,
+            errors: error::field_errors(err.errors),
         }
+        .fail()
     }
 
     async fn delete<S>(&self, path: S) -> Result<(), Error>
@@ -181,19 +572,114 @@ impl Discord {
         S: AsRef<str>,
     {
         let url = self.url(path);
-        let response = self.client.delete(url).send().await?;
+        let request = self.authorized(Method::DELETE, url);
+        let response = self.send(request).await?;
+        self.handle_empty_response(Method::DELETE, response).await
+    }
 
-        if response.status().is_success() {
-            Ok(())
-        } else {
-            let err: DiscordError = response.json().await?;
+    async fn patch_no_content<S, B>(
+        &self,
+        path: S,
+        body: &B,
+    ) -> Result<(), Error>
+    where
+        S: AsRef<str>,
+        B: Serialize,
+    {
+        let url = self.url(path);
+        let request = self.authorized(Method::PATCH, url).json(body);
+        let response = self.send(request).await?;
+        self.handle_empty_response(Method::PATCH, response).await
+    }
 
-            error::Discord {
-                code: err.code,
-                message: err.message,
-            }
-            .fail()
-        }
+    /// Send a `PUT` without a body, for endpoints that respond with
+    /// `204 No Content`.
+    async fn put_empty<S>(&self, path: S) -> Result<(), Error>
+    where
+        S: AsRef<str>,
+    {
+        let url = self.url(path);
+        let request = self.authorized(Method::PUT, url);
+        let response = self.send(request).await?;
+        self.handle_empty_response(Method::PUT, response).await
+    }
+
+    /// Like [`Discord::get`], but without the configured token, for
+    /// endpoints authenticated by a token in the path.
+    async fn get_unauthorized<S, T>(&self, path: S) -> Result<T, Error>
+    where
+        S: AsRef<str>,
+        T: DeserializeOwned,
+    {
+        let url = self.url(path);
+        let request = self.client.get(url);
+        let response = self.send(request).await?;
+        self.handle_response(Method::GET, response).await
+    }
+
+    /// Like [`Discord::patch`], but without the configured token, for
+    /// endpoints authenticated by a token in the path.
+    async fn patch_unauthorized<S, B, T>(
+        &self,
+        path: S,
+        body: &B,
+    ) -> Result<T, Error>
+    where
+        S: AsRef<str>,
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let url = self.url(path);
+        let request = self.client.patch(url).json(body);
+        let response = self.send(request).await?;
+        self.handle_response(Method::PATCH, response).await
+    }
+
+    /// Like [`Discord::post`], but without the configured token, for
+    /// endpoints authenticated by a token in the path.
+    async fn post_unauthorized<S, B, T>(
+        &self,
+        path: S,
+        body: &B,
+    ) -> Result<T, Error>
+    where
+        S: AsRef<str>,
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let url = self.url(path);
+        let request = self.client.post(url).json(body);
+        let response = self.send(request).await?;
+        self.handle_response(Method::POST, response).await
+    }
+
+    /// Like [`Discord::post_unauthorized`], but for endpoints that respond
+    /// with `204 No Content`.
+    async fn post_unauthorized_no_content<S, B>(
+        &self,
+        path: S,
+        body: &B,
+    ) -> Result<(), Error>
+    where
+        S: AsRef<str>,
+        B: Serialize,
+    {
+        let url = self.url(path);
+        let request = self.client.post(url).json(body);
+        let response = self.send(request).await?;
+        self.handle_empty_response(Method::POST, response).await
+    }
+
+    /// Like [`Discord::delete`], but without the configured token, for
+    /// endpoints authenticated by a token in the path.
+    async fn delete_unauthorized<S>(&self, path: S) -> Result<(), Error>
+    where
+        S: AsRef<str>,
+    {
+        let url = self.url(path);
+        let request = self.client.delete(url);
+        let response = self.send(request).await?;
+        self.handle_empty_response(Method::DELETE, response).await
     }
 
     async fn patch<S, B, T>(&self, path: S, body: &B) -> Result<T, Error>
@@ -203,8 +689,9 @@ impl Discord {
         B: Serialize,
     {
         let url = self.url(path);
-        let response = self.client.patch(url).json(body).send().await?;
-        self.handle_response(response).await
+        let request = self.authorized(Method::PATCH, url).json(body);
+        let response = self.send(request).await?;
+        self.handle_response(Method::PATCH, response).await
     }
 
     async fn put<S, B, T>(&self, path: S, body: &B) -> Result<T, Error>
@@ -214,8 +701,33 @@ impl Discord {
         B: Serialize,
     {
         let url = self.url(path);
-        let response = self.client.put(url).json(body).send().await?;
-        self.handle_response(response).await
+        let request = self.authorized(Method::PUT, url).json(body);
+        let response = self.send(request).await?;
+        self.handle_response(Method::PUT, response).await
+    }
+
+    /// Like [`Discord::put`], but returns `None` when Discord responds with
+    /// `204 No Content` instead of a body.
+    async fn put_optional<S, B, T>(
+        &self,
+        path: S,
+        body: &B,
+    ) -> Result<Option<T>, Error>
+    where
+        S: AsRef<str>,
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let url = self.url(path);
+        let request = self.authorized(Method::PUT, url).json(body);
+        let response = self.send(request).await?;
+
+        if response.status() == StatusCode::NO_CONTENT {
+            self.handle_empty_response(Method::PUT, response).await?;
+            Ok(None)
+        } else {
+            self.handle_response(Method::PUT, response).await.map(Some)
+        }
     }
 
     async fn post<S, B, T>(&self, path: S, body: &B) -> Result<T, Error>
@@ -225,8 +737,80 @@ impl Discord {
         B: Serialize,
     {
         let url = self.url(path);
-        let response = self.client.post(url).json(body).send().await?;
-        self.handle_response(response).await
+        let request = self.authorized(Method::POST, url).json(body);
+        let response = self.send(request).await?;
+        self.handle_response(Method::POST, response).await
+    }
+
+    /// Send a `POST` without a body, for endpoints that take no parameters.
+    async fn post_empty<S, T>(&self, path: S) -> Result<T, Error>
+    where
+        S: AsRef<str>,
+        T: DeserializeOwned,
+    {
+        let url = self.url(path);
+        let request = self.authorized(Method::POST, url);
+        let response = self.send(request).await?;
+        self.handle_response(Method::POST, response).await
+    }
+
+    /// Send a form-encoded `POST` without the configured token, for endpoints
+    /// like `oauth2/token` that carry their credentials in the body.
+    async fn post_form_unauthorized<S, B, T>(
+        &self,
+        path: S,
+        body: &B,
+    ) -> Result<T, Error>
+    where
+        S: AsRef<str>,
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let url = self.url(path);
+        let request = self.client.post(url).form(body);
+        let response = self.send(request).await?;
+        self.handle_response(Method::POST, response).await
+    }
+
+    async fn post_multipart<S, T>(
+        &self,
+        path: S,
+        form: multipart::Form,
+    ) -> Result<T, Error>
+    where
+        S: AsRef<str>,
+        T: DeserializeOwned,
+    {
+        let url = self.url(path);
+        let request = self.authorized(Method::POST, url).multipart(form);
+        let response = self.send(request).await?;
+        self.handle_response(Method::POST, response).await
+    }
+
+    /// Fetch a non-JSON body, like an image, as raw bytes.
+    async fn get_bytes<S>(&self, path: S) -> Result<Vec<u8>, Error>
+    where
+        S: AsRef<str>,
+    {
+        let url = self.url(path);
+        let request = self.authorized(Method::GET, url);
+        let response = self.send(request).await?;
+
+        if !response.status().is_success() {
+            let (_, body) = self.read_body(Method::GET, response).await?;
+            return Self::fail(body);
+        }
+
+        let status = response.status();
+        let path = redact_path(response.url().path());
+        let bytes = response.bytes().await?.to_vec();
+
+        if let Some(hook) = &self.response_hook {
+            let shown = format!("[{} bytes]", bytes.len());
+            hook(&Method::GET, &path, status, &shown);
+        }
+
+        Ok(bytes)
     }
 
     async fn get<S, T>(&self, path: S) -> Result<T, Error>
@@ -235,7 +819,476 @@ impl Discord {
         T: DeserializeOwned,
     {
         let url = self.url(path);
-        let response = self.client.get(url).send().await?;
-        self.handle_response(response).await
+        let request = self.authorized(Method::GET, url);
+        let response = self.send(request).await?;
+        self.handle_response(Method::GET, response).await
+    }
+
+    /// Like [`Discord::get`], but with the fields of `query` added to `path`
+    /// as URL parameters, leaving out any that are `None`.
+    async fn get_with_query<S, Q, T>(
+        &self,
+        path: S,
+        query: &Q,
+    ) -> Result<T, Error>
+    where
+        S: AsRef<str>,
+        Q: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let query = query::Query::serialize(query)?;
+        let path = format!("{}{}", path.as_ref(), query);
+        self.get(path).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use crate::mock::{MockResponse, MockServer};
+    use crate::requests::{GetChannelWebhooks, GetCurrentUser};
+
+    use reqwest::header::HeaderMap;
+
+    use serde_json::json;
+
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use super::*;
+
+    fn user_json() -> serde_json::Value {
+        json!({
+            "id": "53908099506183680",
+            "username": "Mason",
+            "discriminator": "9999",
+            "avatar": null
+        })
+    }
+
+    #[tokio::test]
+    async fn timeout_elapses() {
+        let server = MockServer::always(
+            MockResponse::json(200, user_json())
+                .with_delay(Duration::from_millis(500)),
+        );
+
+        let config = Config::builder()
+            .token(Token::bot("mock-token".into()))
+            .api_root(server.api_root())
+            .timeout(Duration::from_millis(50))
+            .build();
+
+        let discord = Discord::new(&config).unwrap();
+
+        let err = GetCurrentUser::builder()
+            .build()
+            .send(&discord)
+            .await
+            .unwrap_err();
+
+        let source =
+            assert_matches!(&err, Error::Reqwest { source, .. } => source);
+        let source = source.downcast_ref::<reqwest::Error>().unwrap();
+        assert!(source.is_timeout());
+    }
+
+    #[tokio::test]
+    async fn discord_error_field_errors() {
+        let server = MockServer::always(MockResponse::json(
+            400,
+            json!({
+                "code": 50035,
+                "message": "Invalid Form Body",
+                "errors": {
+                    "embeds": {
+                        "0": {
+                            "title": {
+                                "_errors": [{
+                                    "code": "BASE_TYPE_MAX_LENGTH",
+                                    "message": "Must be 256 or fewer in length."
+                                }]
+                            }
+                        }
+                    }
+                }
+            }),
+        ));
+
+        let err = GetCurrentUser::builder()
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap_err();
+
+        let (code, errors) = assert_matches!(
+            &err,
+            Error::Discord { code, errors, .. } => (code, errors)
+        );
+
+        assert_eq!(*code, Some(50035));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path(), "embeds.0.title");
+        assert_eq!(errors[0].code(), "BASE_TYPE_MAX_LENGTH");
+    }
+
+    #[tokio::test]
+    async fn deserialize_error_keeps_body() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!({ "id": "53908099506183680", "username": 7 }),
+        ));
+
+        let err = GetCurrentUser::builder()
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap_err();
+
+        let (source, body) = assert_matches!(
+            &err,
+            Error::Deserialize { source, body, .. } => (source, body)
+        );
+
+        assert!(source.is_data());
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(body).unwrap(),
+            json!({ "id": "53908099506183680", "username": 7 })
+        );
+        assert!(err.to_string().contains("invalid type"));
+    }
+
+    #[tokio::test]
+    async fn response_hook() {
+        let server = MockServer::always(MockResponse::json(200, user_json()));
+
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen_hook = seen.clone();
+
+        let config = Config::builder()
+            .token(Token::bot("mock-token".into()))
+            .api_root(server.api_root())
+            .response_hook(Arc::new(move |method, path, status, body| {
+                seen_hook.lock().unwrap().push((
+                    method.clone(),
+                    path.to_owned(),
+                    status,
+                    body.to_owned(),
+                ));
+            }))
+            .build();
+
+        let discord = Discord::new(&config).unwrap();
+
+        GetCurrentUser::builder()
+            .build()
+            .send(&discord)
+            .await
+            .unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 1);
+
+        let (method, path, status, body) = &seen[0];
+        assert_eq!(*method, Method::GET);
+        assert_eq!(path, "/users/@me");
+        assert_eq!(*status, StatusCode::OK);
+        assert!(body.contains("Mason"));
+        assert!(!body.contains("mock-token"));
+    }
+
+    #[tokio::test]
+    async fn response_hook_hides_webhook_tokens() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!([{
+                "type": 1,
+                "id": "223704706495545344",
+                "name": "test webhook",
+                "avatar": null,
+                "channel_id": "199737254929760256",
+                "guild_id": "199737254929760256",
+                "application_id": null,
+                "token": "3d89bb7572e0fb30"
+            }]),
+        ));
+
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen_hook = seen.clone();
+
+        let config = Config::builder()
+            .token(Token::bot("mock-token".into()))
+            .api_root(server.api_root())
+            .response_hook(Arc::new(move |_, _, _, body| {
+                seen_hook.lock().unwrap().push(body.to_owned());
+            }))
+            .build();
+
+        let discord = Discord::new(&config).unwrap();
+
+        let webhooks = GetChannelWebhooks::builder()
+            .channel_id(199737254929760256.into())
+            .build()
+            .send(&discord)
+            .await
+            .unwrap();
+
+        assert_eq!(webhooks[0].token(), Some("3d89bb7572e0fb30"));
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 1);
+        assert!(!seen[0].contains("3d89bb7572e0fb30"));
+        assert!(seen[0].contains("test webhook"));
+        assert!(seen[0].contains("[redacted]"));
+    }
+
+    #[test]
+    fn redact_body_nested_tokens() {
+        let body = r#"{"a":[{"access_token":"x1","refresh_token":"x2"}],"token":null}"#;
+        let shown = redact_body(body);
+
+        assert!(!shown.contains("x1"));
+        assert!(!shown.contains("x2"));
+        assert!(shown.contains(r#""token":null"#));
+
+        assert_eq!(redact_body("not json"), "not json");
+    }
+
+    #[test]
+    fn redact_path_webhook_token() {
+        assert_eq!(
+            redact_path(
+                "/api/webhooks/223704706495545344/3d89bb7572e0fb/messages/1"
+            ),
+            "/api/webhooks/223704706495545344/[redacted]/messages/1"
+        );
+        assert_eq!(
+            redact_path("/api/webhooks/223704706495545344"),
+            "/api/webhooks/223704706495545344"
+        );
+        assert_eq!(redact_path("/users/@me"), "/users/@me");
+    }
+
+    #[tokio::test]
+    async fn client_builder_hook() {
+        let server = MockServer::always(MockResponse::json(200, user_json()));
+
+        let config = Config::builder()
+            .token(Token::bot("mock-token".into()))
+            .api_root(server.api_root())
+            .client_builder(Box::new(|builder| {
+                let mut headers = HeaderMap::new();
+                headers.insert("x-custom", HeaderValue::from_static("yes"));
+                builder.default_headers(headers)
+            }))
+            .build();
+
+        let discord = Discord::new(&config).unwrap();
+
+        GetCurrentUser::builder()
+            .build()
+            .send(&discord)
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.header("x-custom"), Some("yes"));
+    }
+
+    #[tokio::test]
+    async fn current_user_id_is_cached() {
+        let server = MockServer::always(MockResponse::json(200, user_json()));
+        let discord = server.discord();
+
+        let first = discord.current_user_id().await.unwrap();
+        let second = discord.clone().current_user_id().await.unwrap();
+
+        assert_eq!(first, 53908099506183680.into());
+        assert_eq!(second, first);
+
+        let request = server.only_request();
+        assert_eq!(request.path, "/users/@me");
+    }
+
+    #[tokio::test]
+    async fn current_user_id_concurrent() {
+        let server = MockServer::always(
+            MockResponse::json(200, user_json())
+                .with_delay(Duration::from_millis(100)),
+        );
+        let discord = server.discord();
+
+        let (first, second) =
+            tokio::join!(discord.current_user_id(), discord.current_user_id());
+
+        assert_eq!(first.unwrap(), 53908099506183680.into());
+        assert_eq!(second.unwrap(), 53908099506183680.into());
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn send_dm_reuses_channel() {
+        let server = MockServer::start(|request| {
+            if request.path == "/users/@me/channels" {
+                MockResponse::json(
+                    200,
+                    json!({
+                        "id": "319674150115610528",
+                        "type": 1,
+                        "last_message_id": null,
+                        "recipients": [user_json()]
+                    }),
+                )
+            } else {
+                MockResponse::json(
+                    200,
+                    json!({
+                        "id": "334385199974967042",
+                        "channel_id": "319674150115610528",
+                        "author": user_json(),
+                        "content": "hello",
+                        "timestamp": "2017-07-11T17:27:07.299000+00:00",
+                        "edited_timestamp": null,
+                        "tts": false,
+                        "mention_everyone": false,
+                        "mentions": [],
+                        "mention_roles": [],
+                        "attachments": [],
+                        "embeds": [],
+                        "pinned": false,
+                        "type": 0
+                    }),
+                )
+            }
+        });
+
+        let discord = server.discord();
+        let user_id = 53908099506183680.into();
+
+        discord.send_dm(user_id, "hello").await.unwrap();
+        discord.clone().send_dm(user_id, "again").await.unwrap();
+
+        let requests = server.requests();
+        let paths: Vec<_> = requests.iter().map(|r| r.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "/users/@me/channels",
+                "/channels/319674150115610528/messages",
+                "/channels/319674150115610528/messages",
+            ]
+        );
+        assert_eq!(
+            requests[0].json(),
+            json!({ "recipient_id": "53908099506183680" })
+        );
+        assert_eq!(requests[2].json()["content"], "again");
+    }
+
+    #[test]
+    fn api_root_from_version() {
+        let config = Config::builder()
+            .token(Token::bot("mock-token".into()))
+            .api_version(10)
+            .build();
+
+        let discord = Discord::new(&config).unwrap();
+        assert_eq!(
+            discord.url("users/@me").as_str(),
+            "https://discord.com/api/v10/users/@me"
+        );
+    }
+
+    #[test]
+    fn api_root_override_wins() {
+        let config = Config::builder()
+            .token(Token::bot("mock-token".into()))
+            .api_version(9)
+            .api_root("https://proxy.example/api/".into())
+            .build();
+
+        let discord = Discord::new(&config).unwrap();
+        assert_eq!(
+            discord.url("users/@me").as_str(),
+            "https://proxy.example/api/users/@me"
+        );
+    }
+
+    #[tokio::test]
+    async fn global_rate_limit_holds_back_other_requests() {
+        let limited = AtomicBool::new(false);
+        let server = MockServer::start(move |_| {
+            if limited.swap(true, Ordering::SeqCst) {
+                return MockResponse::json(200, user_json());
+            }
+
+            MockResponse::json(
+                429,
+                json!({
+                    "message": "You are being rate limited.",
+                    "retry_after": 0.5,
+                    "global": true
+                }),
+            )
+            .with_header("Retry-After", "0.5")
+            .with_header("X-RateLimit-Global", "true")
+        });
+
+        let discord = server.discord();
+
+        let first = GetCurrentUser::builder().build().send(&discord);
+        let second = async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            let sent = Instant::now();
+            GetCurrentUser::builder().build().send(&discord).await?;
+            Ok::<_, Error>(sent.elapsed())
+        };
+
+        let (first, second) = tokio::join!(first, second);
+
+        first.unwrap();
+        assert!(second.unwrap() >= Duration::from_millis(300));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    fn global_rate_limit(retry_after: &str) -> MockResponse {
+        MockResponse::json(
+            429,
+            json!({
+                "message": "You are being rate limited.",
+                "retry_after": 0,
+                "global": true
+            }),
+        )
+        .with_header("Retry-After", retry_after)
+        .with_header("X-RateLimit-Global", "true")
+    }
+
+    #[tokio::test]
+    async fn global_rate_limit_invalid_retry_after() {
+        for retry_after in &["-1", "NaN", "inf", "1e300", "1e19"] {
+            let server = MockServer::always(global_rate_limit(retry_after));
+
+            let result = GetCurrentUser::builder()
+                .build()
+                .send(&server.discord())
+                .await;
+
+            assert!(result.is_err());
+            assert_eq!(server.requests().len(), 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn global_rate_limit_gives_up() {
+        let server = MockServer::always(global_rate_limit("0"));
+
+        let result = GetCurrentUser::builder()
+            .build()
+            .send(&server.discord())
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(server.requests().len(), MAX_GLOBAL_RETRIES + 1);
     }
 }
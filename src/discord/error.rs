@@ -4,23 +4,138 @@
 
 use snafu::{Backtrace, IntoError, Snafu};
 
+/// A problem with one field of a request body, as reported by Discord.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FieldError {
+    path: String,
+    code: String,
+    message: String,
+}
+
+impl FieldError {
+    /// The dotted path to the offending field, like `embeds.0.title`.
+    ///
+    /// Empty when the error applies to the whole body.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct RawFieldError {
+    code: String,
+    message: String,
+}
+
+/// Sort array indices numerically, ahead of named fields, so that
+/// `embeds.2` comes before `embeds.10`.
+fn key_order(key: &str) -> (bool, Option<u64>, &str) {
+    let index = key.parse::<u64>().ok();
+    (index.is_none(), index, key)
+}
+
+fn flatten_into(
+    value: serde_json::Value,
+    path: &str,
+    out: &mut Vec<FieldError>,
+) {
+    let mut children = match value {
+        serde_json::Value::Object(map) => map,
+        _ => return,
+    };
+
+    if let Some(serde_json::Value::Array(errors)) = children.remove("_errors") {
+        let errors = errors
+            .into_iter()
+            .filter_map(|e| serde_json::from_value::<RawFieldError>(e).ok());
+
+        out.extend(errors.map(|e| FieldError {
+            path: path.to_owned(),
+            code: e.code,
+            message: e.message,
+        }));
+    }
+
+    let mut children: Vec<_> = children.into_iter().collect();
+    children.sort_by(|(a, _), (b, _)| key_order(a).cmp(&key_order(b)));
+
+    for (key, child) in children {
+        let child_path = if path.is_empty() {
+            key
+        } else {
+            format!("{}.{}", path, key)
+        };
+
+        flatten_into(child, &child_path, out);
+    }
+}
+
+/// Flatten Discord's nested `errors` object into one entry per field.
+///
+/// Anything that doesn't match the documented shape is skipped, without
+/// losing the errors around it.
+pub(super) fn field_errors(
+    errors: Option<serde_json::Value>,
+) -> Vec<FieldError> {
+    let mut out = Vec::new();
+
+    if let Some(errors) = errors {
+        flatten_into(errors, "", &mut out);
+    }
+
+    out
+}
+
 #[derive(Debug, Snafu)]
 #[snafu(visibility = "pub(super)")]
 #[non_exhaustive]
 pub enum Error {
     InvalidConfig {
-        source: Box<dyn std::error::Error + 'static>,
+        source: Box<dyn std::error::Error + Send + Sync + 'static>,
         backtrace: Backtrace,
     },
 
     Reqwest {
-        source: Box<dyn std::error::Error + 'static>,
+        source: Box<dyn std::error::Error + Send + Sync + 'static>,
         backtrace: Backtrace,
     },
 
     Discord {
         code: Option<u64>,
         message: Option<String>,
+        /// Per-field details, when Discord rejected the request body.
+        errors: Vec<FieldError>,
+        backtrace: Backtrace,
+    },
+
+    /// A successful response whose body didn't match the expected shape.
+    #[snafu(display("unable to deserialize response: {}", source))]
+    Deserialize {
+        source: serde_json::Error,
+        /// The raw response body.
+        body: String,
+        backtrace: Backtrace,
+    },
+
+    /// A request's query parameters couldn't be encoded.
+    #[snafu(display("unable to encode query: {}", message))]
+    QueryEncode {
+        message: String,
+        backtrace: Backtrace,
+    },
+
+    #[snafu(display("invalid `{}`: {}", field, message))]
+    Validation {
+        field: &'static str,
+        message: String,
         backtrace: Backtrace,
     },
 }
@@ -36,3 +151,110 @@ impl From<reqwest::Error> for Error {
         Reqwest {}.into_error(Box::new(err))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn field_errors_nested() {
+        let json = json!({
+            "activities": {
+                "0": {
+                    "platform": {
+                        "_errors": [
+                            {
+                                "code": "BASE_TYPE_CHOICES",
+                                "message": "Value must be one of {'desktop', 'android', 'ios'}."
+                            }
+                        ]
+                    },
+                    "type": {
+                        "_errors": [
+                            {
+                                "code": "BASE_TYPE_CHOICES",
+                                "message": "Value must be one of {0, 1, 2, 3, 4, 5}."
+                            }
+                        ]
+                    }
+                }
+            }
+        });
+
+        let errors = field_errors(Some(json));
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].path(), "activities.0.platform");
+        assert_eq!(errors[0].code(), "BASE_TYPE_CHOICES");
+        assert_eq!(
+            errors[0].message(),
+            "Value must be one of {'desktop', 'android', 'ios'}."
+        );
+        assert_eq!(errors[1].path(), "activities.0.type");
+    }
+
+    #[test]
+    fn field_errors_whole_body() {
+        let json = json!({
+            "_errors": [
+                {
+                    "code": "APPLICATION_COMMAND_TOO_LARGE",
+                    "message": "Command exceeds maximum size (4000)"
+                }
+            ]
+        });
+
+        let errors = field_errors(Some(json));
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path(), "");
+        assert_eq!(errors[0].code(), "APPLICATION_COMMAND_TOO_LARGE");
+    }
+
+    fn leaf(code: &str) -> serde_json::Value {
+        json!({ "_errors": [{ "code": code, "message": "Invalid" }] })
+    }
+
+    #[test]
+    fn field_errors_array_indices_in_order() {
+        let embeds: serde_json::Map<_, _> = (0..12)
+            .map(|idx| (idx.to_string(), json!({ "title": leaf("BAD") })))
+            .collect();
+
+        let errors = field_errors(Some(json!({ "embeds": embeds })));
+
+        let paths: Vec<_> = errors.iter().map(FieldError::path).collect();
+        let expected: Vec<_> =
+            (0..12).map(|idx| format!("embeds.{}.title", idx)).collect();
+
+        assert_eq!(paths, expected);
+    }
+
+    #[test]
+    fn field_errors_skip_unexpected_sibling() {
+        let json = json!({
+            "content": leaf("BASE_TYPE_MAX_LENGTH"),
+            "surprise": 7,
+            "embeds": {
+                "0": { "title": leaf("BASE_TYPE_REQUIRED") },
+                "1": "unexpected"
+            }
+        });
+
+        let errors = field_errors(Some(json));
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].path(), "content");
+        assert_eq!(errors[0].code(), "BASE_TYPE_MAX_LENGTH");
+        assert_eq!(errors[1].path(), "embeds.0.title");
+        assert_eq!(errors[1].code(), "BASE_TYPE_REQUIRED");
+    }
+
+    #[test]
+    fn field_errors_missing_or_malformed() {
+        assert!(field_errors(None).is_empty());
+        assert!(field_errors(Some(json!({"a": 1}))).is_empty());
+    }
+}
@@ -0,0 +1,87 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Lazily stream every item from endpoints that page by snowflake cursor.
+
+use futures_util::stream::{self, Stream};
+
+use std::collections::VecDeque;
+
+use super::requests::Request;
+use super::{Discord, Error};
+
+/// A list request that can be continued from the items of a page.
+pub trait Paginate: Request {
+    type Item;
+
+    /// Take the items out of a response to this request.
+    fn items(page: Self::Output) -> Vec<Self::Item>;
+
+    /// The maximum number of items Discord returns per page for this request.
+    ///
+    /// A page shorter than this is assumed to be the last one.
+    fn page_size(&self) -> usize;
+
+    /// Build the request for the page following `page`, which is never
+    /// empty.
+    fn next_page(&self, page: &[Self::Item]) -> Self;
+}
+
+/// Streams every item across all pages of a [`Paginate`] request.
+#[derive(Debug, Clone)]
+pub struct Paginator<R> {
+    discord: Discord,
+    request: R,
+}
+
+impl<R> Paginator<R>
+where
+    R: Paginate + Send + Sync + 'static,
+    R::Item: Send,
+{
+    pub fn new(discord: &Discord, request: R) -> Self {
+        Self {
+            discord: discord.clone(),
+            request,
+        }
+    }
+
+    /// Fetch pages on demand, yielding each item in turn.
+    ///
+    /// The stream ends after a short page, or after yielding an error.
+    pub fn all(self) -> impl Stream<Item = Result<R::Item, Error>> + Send {
+        let state = State {
+            discord: self.discord,
+            next: Some(self.request),
+            buffer: VecDeque::new(),
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(item) = state.buffer.pop_front() {
+                    return Some((Ok(item), state));
+                }
+
+                let request = state.next.take()?;
+
+                let page = match request.execute(&state.discord).await {
+                    Ok(page) => R::items(page),
+                    Err(e) => return Some((Err(e), state)),
+                };
+
+                if !page.is_empty() && page.len() >= request.page_size() {
+                    state.next = Some(request.next_page(&page));
+                }
+
+                state.buffer.extend(page);
+            }
+        })
+    }
+}
+
+struct State<R: Paginate> {
+    discord: Discord,
+    next: Option<R>,
+    buffer: VecDeque<R::Item>,
+}
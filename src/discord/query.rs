@@ -0,0 +1,217 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chrono::{DateTime, SecondsFormat, TimeZone};
+
+use serde::Serialize;
+
+use std::fmt::{self, Display, Write};
+
+use super::{error, Error};
+
+/// Accumulates optional query parameters for a request path.
+///
+/// Displays as `?key=value&...` with each value percent-encoded, or as an
+/// empty string when no parameters were set.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Query {
+    pairs: Vec<(String, String)>,
+}
+
+impl Query {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `key=value` if `value` is `Some`.
+    pub(crate) fn push<V>(mut self, key: &'static str, value: Option<V>) -> Self
+    where
+        V: Display,
+    {
+        if let Some(value) = value {
+            self.pairs.push((key.to_owned(), value.to_string()));
+        }
+
+        self
+    }
+
+    /// Collect the fields of `query`, which must serialize as a struct of
+    /// plain values, in order. `None` fields are left out.
+    pub(crate) fn serialize<Q>(query: &Q) -> Result<Self, Error>
+    where
+        Q: Serialize + ?Sized,
+    {
+        let encoded = serde_urlencoded::to_string(query).map_err(|e| {
+            error::QueryEncode {
+                message: e.to_string(),
+            }
+            .build()
+        })?;
+
+        // Decoded again so that every value is encoded the same way,
+        // by `Display` below.
+        let pairs = form_urlencoded::parse(encoded.as_bytes())
+            .into_owned()
+            .collect();
+
+        Ok(Self { pairs })
+    }
+}
+
+impl Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, (key, value)) in self.pairs.iter().enumerate() {
+            f.write_char(if idx == 0 { '?' } else { '&' })?;
+            f.write_str(key)?;
+            f.write_char('=')?;
+
+            for byte in value.bytes() {
+                match byte {
+                    b'A'..=b'Z'
+                    | b'a'..=b'z'
+                    | b'0'..=b'9'
+                    | b'-'
+                    | b'.'
+                    | b'_'
+                    | b'~' => f.write_char(byte as char)?,
+                    other => write!(f, "%{:02X}", other)?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Format `dt` as an RFC 3339 timestamp for a query parameter, keeping its
+/// offset, like `2021-06-01T12:30:00.000+05:30`.
+pub(crate) fn timestamp<Tz>(dt: &DateTime<Tz>) -> String
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    dt.to_rfc3339_opts(SecondsFormat::Millis, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let query = Query::new().push::<u64>("limit", None);
+        assert_eq!(query.to_string(), "");
+    }
+
+    #[test]
+    fn skips_none() {
+        let query = Query::new()
+            .push("before", Some(12))
+            .push::<u64>("after", None)
+            .push("limit", Some(50));
+
+        assert_eq!(query.to_string(), "?before=12&limit=50");
+    }
+
+    #[test]
+    fn percent_encodes_values() {
+        let query = Query::new()
+            .push("query", Some("a b&c=d/é"))
+            .push("safe", Some("A-z_0.9~"));
+
+        assert_eq!(
+            query.to_string(),
+            "?query=a%20b%26c%3Dd%2F%C3%A9&safe=A-z_0.9~"
+        );
+    }
+
+    #[test]
+    fn percent_encodes_unicode() {
+        let query = Query::new().push("query", Some("é 🔥"));
+
+        assert_eq!(query.to_string(), "?query=%C3%A9%20%F0%9F%94%A5");
+    }
+
+    #[test]
+    fn timestamp_keeps_offset() {
+        let dt =
+            DateTime::parse_from_rfc3339("2021-06-01T12:30:00+05:30").unwrap();
+        assert_eq!(timestamp(&dt), "2021-06-01T12:30:00.000+05:30");
+
+        let utc = dt.with_timezone(&chrono::Utc);
+        assert_eq!(timestamp(&utc), "2021-06-01T07:00:00.000+00:00");
+    }
+
+    #[test]
+    fn timestamp_in_query() {
+        let dt =
+            DateTime::parse_from_rfc3339("2021-06-01T12:30:00+05:30").unwrap();
+        let query = Query::new().push("before", Some(timestamp(&dt)));
+
+        assert_eq!(
+            query.to_string(),
+            "?before=2021-06-01T12%3A30%3A00.000%2B05%3A30"
+        );
+    }
+
+    #[derive(Serialize)]
+    struct Params<'a> {
+        before: Option<u64>,
+        query: Option<&'a str>,
+        limit: Option<u64>,
+    }
+
+    #[test]
+    fn serialize_empty() {
+        let params = Params {
+            before: None,
+            query: None,
+            limit: None,
+        };
+
+        let query = Query::serialize(&params).unwrap();
+        assert_eq!(query.to_string(), "");
+    }
+
+    #[test]
+    fn serialize_skips_none() {
+        let params = Params {
+            before: Some(12),
+            query: None,
+            limit: Some(50),
+        };
+
+        let query = Query::serialize(&params).unwrap();
+        assert_eq!(query.to_string(), "?before=12&limit=50");
+    }
+
+    #[test]
+    fn serialize_unicode() {
+        let params = Params {
+            before: None,
+            query: Some("a b&c=d/é 🔥"),
+            limit: None,
+        };
+
+        let query = Query::serialize(&params).unwrap();
+        assert_eq!(
+            query.to_string(),
+            "?query=a%20b%26c%3Dd%2F%C3%A9%20%F0%9F%94%A5"
+        );
+    }
+
+    #[test]
+    fn serialize_rejects_nested() {
+        #[derive(Serialize)]
+        struct Nested {
+            ids: Vec<u64>,
+        }
+
+        let err = Query::serialize(&Nested { ids: vec![1] }).unwrap_err();
+        assert!(matches!(err, Error::QueryEncode { .. }));
+
+        let err = Query::serialize(&7).unwrap_err();
+        assert!(matches!(err, Error::QueryEncode { .. }));
+    }
+}
@@ -1,38 +1,183 @@
 use crate::enums::IntegerEnum;
 use crate::image::UploadImage;
+use crate::oauth2::{AccessToken, Grant, NewToken};
+use crate::permissions::RoleId;
 use crate::resources::application::{
-    ApplicationCommand, ApplicationCommandId, ApplicationCommandOption,
+    Application, ApplicationCommand, ApplicationCommandId,
+    ApplicationCommandKind, ApplicationCommandOption,
     ApplicationCommandPermission, ApplicationId, EditApplicationCommand,
     EditGuildApplicationCommandPermissions, GuildApplicationCommandPermissions,
-    NewApplicationCommand,
+    InteractionToken, NewApplicationCommand, RoleConnectionMetadata,
+};
+use crate::resources::audit_log::{
+    AuditLog, AuditLogEntry, AuditLogEntryId, AuditLogEvent,
+};
+use crate::resources::auto_moderation::{
+    AutoModerationAction, AutoModerationEventKind, AutoModerationRule,
+    AutoModerationRuleId, AutoModerationTriggerKind, EditAutoModerationRule,
+    NewAutoModerationRule, TriggerMetadata,
 };
-use crate::resources::audit_log::{AuditLog, AuditLogEntryId, AuditLogEvent};
 use crate::resources::channel::{
-    Channel, ChannelId, ChannelKind, EditChannel, Message, MessageId,
-    Overwrite, VideoQualityMode,
+    AllowedMentions, Channel, ChannelId, ChannelKind, Component, EditChannel,
+    EditGroupDm, EditMessage, EditSticker, Embed, ExistingAttachment,
+    FollowedChannel, Message, MessageId, MessageReference, NewChannel,
+    NewChannelFollower, NewDm, NewMessage, Nonce, Overwrite, Sticker,
+    StickerId, StickerPack, StickerPacks, ThreadList, VideoQualityMode,
+};
+use crate::resources::emoji::{EditEmoji, Emoji, EmojiId, NewEmoji};
+use crate::resources::guild::{
+    EditCurrentMember, EditGuildMember, EditWelcomeScreen, GuildId,
+    GuildMember, GuildPreview, NewGuildMember, WelcomeScreen,
+    WelcomeScreenChannel, WidgetStyle,
+};
+use crate::resources::guild_scheduled_event::{
+    EditScheduledEvent, EntityMetadata, GuildScheduledEvent,
+    GuildScheduledEventId, NewScheduledEvent, ScheduledEventEntityKind,
+    ScheduledEventPrivacyLevel, ScheduledEventStatus, ScheduledEventUser,
 };
-use crate::resources::guild::GuildId;
-use crate::resources::user::{User, UserId};
+use crate::resources::invite::Invite;
+use crate::resources::user::{Connection, User, UserId};
+use crate::resources::voice::{EditVoiceState, VoiceRegion};
+use crate::resources::webhook::{NewWebhookMessage, Webhook, WebhookId};
+use crate::str::obscure;
+
+use chrono::{DateTime, FixedOffset};
+
+use educe::Educe;
+
+use reqwest::multipart::{Form, Part};
 
 use serde::Serialize;
 
-use super::{Discord, Error};
+use std::collections::HashMap;
+use std::future::Future;
+use std::ops::RangeInclusive;
+use std::pin::Pin;
+
+use super::paginate::Paginate;
+use super::query::{self, Query};
+use super::{error, Discord, Error};
 
 use typed_builder::TypedBuilder;
 
+/// A boxed future resolving to the result of a [`Request`].
+pub type BoxFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<T, Error>> + Send + 'a>>;
+
+/// A request that can be sent to Discord.
+///
+/// Every request builder implements this trait, which makes it possible to
+/// write generic wrappers (for retries, logging, and so on) around arbitrary
+/// requests. Use [`Discord::request`] to send any `Request`.
+pub trait Request {
+    /// The type Discord responds with.
+    type Output;
+
+    /// Send the request, without consuming it.
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output>;
+}
+
+/// Get the application that owns the current bot token.
+///
+/// Useful for discovering the [`ApplicationId`] needed by the application
+/// command requests.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetCurrentApplication {
+    #[builder(default, setter(skip))]
+    _p: (),
+}
+
+impl Request for GetCurrentApplication {
+    type Output = Application;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = "oauth2/applications/@me";
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetCurrentApplication {
+    pub async fn send(self, discord: &Discord) -> Result<Application, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Obtain an OAuth2 access token, either by exchanging an authorization code
+/// or by using a refresh token.
+#[derive(Educe, Clone, TypedBuilder)]
+#[educe(Debug)]
+pub struct TokenExchange {
+    #[builder(setter(into))]
+    client_id: ApplicationId,
+
+    #[builder(setter(into))]
+    #[educe(Debug(method = "obscure"))]
+    client_secret: String,
+
+    grant: Grant,
+}
+
+impl Request for TokenExchange {
+    type Output = AccessToken;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = "oauth2/token";
+
+            let body = NewToken::new(
+                self.client_id,
+                self.client_secret.clone(),
+                self.grant.clone(),
+            );
+
+            discord.post_form_unauthorized(path, &body).await
+        })
+    }
+}
+
+impl TokenExchange {
+    pub async fn send(self, discord: &Discord) -> Result<AccessToken, Error> {
+        self.execute(discord).await
+    }
+}
+
 #[derive(Debug, Clone, TypedBuilder)]
 pub struct GetGlobalApplicationCommands {
     #[builder(setter(into))]
     application_id: ApplicationId,
 }
 
+impl Request for GetGlobalApplicationCommands {
+    type Output = Vec<ApplicationCommand>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("applications/{}/commands", self.application_id);
+            discord.get(path).await
+        })
+    }
+}
+
 impl GetGlobalApplicationCommands {
     pub async fn send(
         self,
         discord: &Discord,
     ) -> Result<Vec<ApplicationCommand>, Error> {
-        let path = format!("applications/{}/commands", self.application_id);
-        discord.get(path).await
+        self.execute(discord).await
     }
 }
 
@@ -43,16 +188,29 @@ pub struct GetGlobalApplicationCommand {
     command_id: ApplicationCommandId,
 }
 
+impl Request for GetGlobalApplicationCommand {
+    type Output = ApplicationCommand;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "applications/{}/commands/{}",
+                self.application_id, self.command_id
+            );
+            discord.get(path).await
+        })
+    }
+}
+
 impl GetGlobalApplicationCommand {
     pub async fn send(
         self,
         discord: &Discord,
     ) -> Result<ApplicationCommand, Error> {
-        let path = format!(
-            "applications/{}/commands/{}",
-            self.application_id, self.command_id
-        );
-        discord.get(path).await
+        self.execute(discord).await
     }
 }
 
@@ -65,13 +223,26 @@ pub struct BulkOverwriteGlobalApplicationCommands {
     commands: Vec<NewApplicationCommand>,
 }
 
+impl Request for BulkOverwriteGlobalApplicationCommands {
+    type Output = Vec<ApplicationCommand>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("applications/{}/commands", self.application_id);
+            discord.put(path, &self.commands).await
+        })
+    }
+}
+
 impl BulkOverwriteGlobalApplicationCommands {
     pub async fn send(
         self,
         discord: &Discord,
     ) -> Result<Vec<ApplicationCommand>, Error> {
-        let path = format!("applications/{}/commands", self.application_id);
-        discord.put(path, &self.commands).await
+        self.execute(discord).await
     }
 }
 
@@ -80,12 +251,23 @@ pub struct CreateGlobalApplicationCommand {
     #[builder(setter(into))]
     application_id: ApplicationId,
 
+    #[builder(default, setter(strip_option, into))]
+    kind: Option<IntegerEnum<ApplicationCommandKind>>,
+
     #[builder(setter(into))]
     name: String,
 
-    #[builder(setter(into))]
+    #[builder(default, setter(strip_option, into))]
+    name_localizations: Option<HashMap<String, String>>,
+
+    /// Required for chat input commands; must be empty for user and message
+    /// commands.
+    #[builder(default, setter(into))]
     description: String,
 
+    #[builder(default, setter(strip_option, into))]
+    description_localizations: Option<HashMap<String, String>>,
+
     #[builder(default, setter(strip_option, into))]
     options: Option<Vec<ApplicationCommandOption>>,
 
@@ -93,20 +275,38 @@ pub struct CreateGlobalApplicationCommand {
     default_permission: Option<bool>,
 }
 
+impl Request for CreateGlobalApplicationCommand {
+    type Output = ApplicationCommand;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let new_command = NewApplicationCommand {
+                kind: self.kind,
+                name: self.name.clone(),
+                name_localizations: self.name_localizations.clone(),
+                description: self.description.clone(),
+                description_localizations: self
+                    .description_localizations
+                    .clone(),
+                options: self.options.clone(),
+                default_permission: self.default_permission,
+            };
+
+            let path = format!("applications/{}/commands", self.application_id);
+            discord.post(path, &new_command).await
+        })
+    }
+}
+
 impl CreateGlobalApplicationCommand {
     pub async fn send(
         self,
         discord: &Discord,
     ) -> Result<ApplicationCommand, Error> {
-        let new_command = NewApplicationCommand {
-            name: self.name,
-            description: self.description,
-            options: self.options,
-            default_permission: self.default_permission,
-        };
-
-        let path = format!("applications/{}/commands", self.application_id);
-        discord.post(path, &new_command).await
+        self.execute(discord).await
     }
 }
 
@@ -119,9 +319,15 @@ pub struct EditGlobalApplicationCommand {
     #[builder(default, setter(into, strip_option))]
     name: Option<String>,
 
+    #[builder(default, setter(into, strip_option))]
+    name_localizations: Option<HashMap<String, String>>,
+
     #[builder(default, setter(into, strip_option))]
     description: Option<String>,
 
+    #[builder(default, setter(into, strip_option))]
+    description_localizations: Option<HashMap<String, String>>,
+
     #[builder(default, setter(strip_option, into))]
     options: Option<Vec<ApplicationCommandOption>>,
 
@@ -129,23 +335,40 @@ pub struct EditGlobalApplicationCommand {
     default_permission: Option<bool>,
 }
 
+impl Request for EditGlobalApplicationCommand {
+    type Output = ApplicationCommand;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let edit_command = EditApplicationCommand {
+                name: self.name.clone(),
+                name_localizations: self.name_localizations.clone(),
+                description: self.description.clone(),
+                description_localizations: self
+                    .description_localizations
+                    .clone(),
+                options: self.options.clone(),
+                default_permission: self.default_permission,
+            };
+
+            let path = format!(
+                "applications/{}/commands/{}",
+                self.application_id, self.command_id
+            );
+            discord.patch(path, &edit_command).await
+        })
+    }
+}
+
 impl EditGlobalApplicationCommand {
     pub async fn send(
         self,
         discord: &Discord,
     ) -> Result<ApplicationCommand, Error> {
-        let edit_command = EditApplicationCommand {
-            name: self.name,
-            description: self.description,
-            options: self.options,
-            default_permission: self.default_permission,
-        };
-
-        let path = format!(
-            "applications/{}/commands/{}",
-            self.application_id, self.command_id
-        );
-        discord.patch(path, &edit_command).await
+        self.execute(discord).await
     }
 }
 
@@ -156,13 +379,97 @@ pub struct DeleteGlobalApplicationCommand {
     command_id: ApplicationCommandId,
 }
 
+impl Request for DeleteGlobalApplicationCommand {
+    type Output = ();
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "applications/{}/commands/{}",
+                self.application_id, self.command_id
+            );
+            discord.delete(path).await
+        })
+    }
+}
+
 impl DeleteGlobalApplicationCommand {
     pub async fn send(self, discord: &Discord) -> Result<(), Error> {
-        let path = format!(
-            "applications/{}/commands/{}",
-            self.application_id, self.command_id
-        );
-        discord.delete(path).await
+        self.execute(discord).await
+    }
+}
+
+/// Get the metadata an application reports for linked roles.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetApplicationRoleConnectionMetadataRecords {
+    #[builder(setter(into))]
+    application_id: ApplicationId,
+}
+
+impl Request for GetApplicationRoleConnectionMetadataRecords {
+    type Output = Vec<RoleConnectionMetadata>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "applications/{}/role-connections/metadata",
+                self.application_id
+            );
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetApplicationRoleConnectionMetadataRecords {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<Vec<RoleConnectionMetadata>, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Replace the metadata an application reports for linked roles.
+///
+/// At most five records are allowed.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct UpdateApplicationRoleConnectionMetadataRecords {
+    #[builder(setter(into))]
+    application_id: ApplicationId,
+
+    #[builder(setter(into))]
+    records: Vec<RoleConnectionMetadata>,
+}
+
+impl Request for UpdateApplicationRoleConnectionMetadataRecords {
+    type Output = Vec<RoleConnectionMetadata>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "applications/{}/role-connections/metadata",
+                self.application_id
+            );
+            discord.put(path, &self.records).await
+        })
+    }
+}
+
+impl UpdateApplicationRoleConnectionMetadataRecords {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<Vec<RoleConnectionMetadata>, Error> {
+        self.execute(discord).await
     }
 }
 
@@ -171,6 +478,31 @@ pub struct GetGuildApplicationCommands {
     #[builder(setter(into))]
     application_id: ApplicationId,
     guild_id: GuildId,
+
+    /// Include every localization, instead of only the one for the
+    /// requesting user's locale.
+    #[builder(default, setter(strip_option))]
+    with_localizations: Option<bool>,
+}
+
+impl Request for GetGuildApplicationCommands {
+    type Output = Vec<ApplicationCommand>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let query = Query::new()
+                .push("with_localizations", self.with_localizations);
+
+            let path = format!(
+                "applications/{}/guilds/{}/commands{}",
+                self.application_id, self.guild_id, query
+            );
+            discord.get(path).await
+        })
+    }
 }
 
 impl GetGuildApplicationCommands {
@@ -178,11 +510,73 @@ impl GetGuildApplicationCommands {
         self,
         discord: &Discord,
     ) -> Result<Vec<ApplicationCommand>, Error> {
-        let path = format!(
-            "applications/{}/guilds/{}/commands",
-            self.application_id, self.guild_id
-        );
-        discord.get(path).await
+        self.execute(discord).await
+    }
+}
+
+/// Register a guild's commands, but only overwrite them when they differ
+/// from what's already registered.
+///
+/// Bots often register the same commands on every start. This fetches the
+/// existing commands first, skipping the overwrite (and its rate limit) when
+/// nothing changed. Either way, the guild's commands are returned.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct SyncGuildApplicationCommands {
+    #[builder(setter(into))]
+    application_id: ApplicationId,
+    guild_id: GuildId,
+
+    #[builder(setter(into))]
+    commands: Vec<NewApplicationCommand>,
+}
+
+impl Request for SyncGuildApplicationCommands {
+    type Output = Vec<ApplicationCommand>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let existing = GetGuildApplicationCommands::builder()
+                .application_id(self.application_id)
+                .guild_id(self.guild_id)
+                .with_localizations(true)
+                .build()
+                .send(discord)
+                .await?;
+
+            if self.unchanged(&existing) {
+                return Ok(existing);
+            }
+
+            BulkOverwriteGuildApplicationCommands::builder()
+                .application_id(self.application_id)
+                .guild_id(self.guild_id)
+                .commands(self.commands.clone())
+                .build()
+                .send(discord)
+                .await
+        })
+    }
+}
+
+impl SyncGuildApplicationCommands {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<Vec<ApplicationCommand>, Error> {
+        self.execute(discord).await
+    }
+
+    fn unchanged(&self, existing: &[ApplicationCommand]) -> bool {
+        existing.len() == self.commands.len()
+            && self.commands.iter().all(|new| {
+                existing
+                    .iter()
+                    .filter(|e| e.name() == new.name)
+                    .any(|e| new.matches(e))
+            })
     }
 }
 
@@ -194,16 +588,29 @@ pub struct GetGuildApplicationCommand {
     guild_id: GuildId,
 }
 
+impl Request for GetGuildApplicationCommand {
+    type Output = ApplicationCommand;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "applications/{}/guilds/{}/commands/{}",
+                self.application_id, self.guild_id, self.command_id
+            );
+            discord.get(path).await
+        })
+    }
+}
+
 impl GetGuildApplicationCommand {
     pub async fn send(
         self,
         discord: &Discord,
     ) -> Result<ApplicationCommand, Error> {
-        let path = format!(
-            "applications/{}/guilds/{}/commands/{}",
-            self.application_id, self.guild_id, self.command_id
-        );
-        discord.get(path).await
+        self.execute(discord).await
     }
 }
 
@@ -217,16 +624,29 @@ pub struct BulkOverwriteGuildApplicationCommands {
     commands: Vec<NewApplicationCommand>,
 }
 
+impl Request for BulkOverwriteGuildApplicationCommands {
+    type Output = Vec<ApplicationCommand>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "applications/{}/guilds/{}/commands",
+                self.application_id, self.guild_id
+            );
+            discord.put(path, &self.commands).await
+        })
+    }
+}
+
 impl BulkOverwriteGuildApplicationCommands {
     pub async fn send(
         self,
         discord: &Discord,
     ) -> Result<Vec<ApplicationCommand>, Error> {
-        let path = format!(
-            "applications/{}/guilds/{}/commands",
-            self.application_id, self.guild_id
-        );
-        discord.put(path, &self.commands).await
+        self.execute(discord).await
     }
 }
 
@@ -236,12 +656,23 @@ pub struct CreateGuildApplicationCommand {
     application_id: ApplicationId,
     guild_id: GuildId,
 
+    #[builder(default, setter(strip_option, into))]
+    kind: Option<IntegerEnum<ApplicationCommandKind>>,
+
     #[builder(setter(into))]
     name: String,
 
-    #[builder(setter(into))]
+    #[builder(default, setter(strip_option, into))]
+    name_localizations: Option<HashMap<String, String>>,
+
+    /// Required for chat input commands; must be empty for user and message
+    /// commands.
+    #[builder(default, setter(into))]
     description: String,
 
+    #[builder(default, setter(strip_option, into))]
+    description_localizations: Option<HashMap<String, String>>,
+
     #[builder(default, setter(strip_option, into))]
     options: Option<Vec<ApplicationCommandOption>>,
 
@@ -249,23 +680,41 @@ pub struct CreateGuildApplicationCommand {
     default_permission: Option<bool>,
 }
 
+impl Request for CreateGuildApplicationCommand {
+    type Output = ApplicationCommand;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let new_command = NewApplicationCommand {
+                kind: self.kind,
+                name: self.name.clone(),
+                name_localizations: self.name_localizations.clone(),
+                description: self.description.clone(),
+                description_localizations: self
+                    .description_localizations
+                    .clone(),
+                options: self.options.clone(),
+                default_permission: self.default_permission,
+            };
+
+            let path = format!(
+                "applications/{}/guilds/{}/commands",
+                self.application_id, self.guild_id
+            );
+            discord.post(path, &new_command).await
+        })
+    }
+}
+
 impl CreateGuildApplicationCommand {
     pub async fn send(
         self,
         discord: &Discord,
     ) -> Result<ApplicationCommand, Error> {
-        let new_command = NewApplicationCommand {
-            name: self.name,
-            description: self.description,
-            options: self.options,
-            default_permission: self.default_permission,
-        };
-
-        let path = format!(
-            "applications/{}/guilds/{}/commands",
-            self.application_id, self.guild_id
-        );
-        discord.post(path, &new_command).await
+        self.execute(discord).await
     }
 }
 
@@ -279,9 +728,15 @@ pub struct EditGuildApplicationCommand {
     #[builder(default, setter(into, strip_option))]
     name: Option<String>,
 
+    #[builder(default, setter(into, strip_option))]
+    name_localizations: Option<HashMap<String, String>>,
+
     #[builder(default, setter(into, strip_option))]
     description: Option<String>,
 
+    #[builder(default, setter(into, strip_option))]
+    description_localizations: Option<HashMap<String, String>>,
+
     #[builder(default, setter(strip_option, into))]
     options: Option<Vec<ApplicationCommandOption>>,
 
@@ -289,23 +744,40 @@ pub struct EditGuildApplicationCommand {
     default_permission: Option<bool>,
 }
 
+impl Request for EditGuildApplicationCommand {
+    type Output = ApplicationCommand;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let edit_command = EditApplicationCommand {
+                name: self.name.clone(),
+                name_localizations: self.name_localizations.clone(),
+                description: self.description.clone(),
+                description_localizations: self
+                    .description_localizations
+                    .clone(),
+                options: self.options.clone(),
+                default_permission: self.default_permission,
+            };
+
+            let path = format!(
+                "applications/{}/guilds/{}/commands/{}",
+                self.application_id, self.guild_id, self.command_id
+            );
+            discord.patch(path, &edit_command).await
+        })
+    }
+}
+
 impl EditGuildApplicationCommand {
     pub async fn send(
         self,
         discord: &Discord,
     ) -> Result<ApplicationCommand, Error> {
-        let edit_command = EditApplicationCommand {
-            name: self.name,
-            description: self.description,
-            options: self.options,
-            default_permission: self.default_permission,
-        };
-
-        let path = format!(
-            "applications/{}/guilds/{}/commands/{}",
-            self.application_id, self.guild_id, self.command_id
-        );
-        discord.patch(path, &edit_command).await
+        self.execute(discord).await
     }
 }
 
@@ -317,21 +789,90 @@ pub struct DeleteGuildApplicationCommand {
     command_id: ApplicationCommandId,
 }
 
+impl Request for DeleteGuildApplicationCommand {
+    type Output = ();
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "applications/{}/guilds/{}/commands/{}",
+                self.application_id, self.guild_id, self.command_id
+            );
+            discord.delete(path).await
+        })
+    }
+}
+
 impl DeleteGuildApplicationCommand {
     pub async fn send(self, discord: &Discord) -> Result<(), Error> {
-        let path = format!(
-            "applications/{}/guilds/{}/commands/{}",
-            self.application_id, self.guild_id, self.command_id
-        );
-        discord.delete(path).await
+        self.execute(discord).await
     }
 }
 
 // TODO: CreateInteractionResponse
-// TODO: GetOriginalInteractionResponse
+
+/// Get the message sent as the initial response to an interaction.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetOriginalInteractionResponse {
+    #[builder(setter(into))]
+    interaction: InteractionToken,
+}
+
+impl Request for GetOriginalInteractionResponse {
+    type Output = Message;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = self.interaction.message_path("@original");
+            discord.get_unauthorized(path).await
+        })
+    }
+}
+
+impl GetOriginalInteractionResponse {
+    pub async fn send(self, discord: &Discord) -> Result<Message, Error> {
+        self.execute(discord).await
+    }
+}
+
 // TODO: EditOriginalInteractionResponse
 // TODO: DeleteOriginalInteractionResponse
 // TODO: CreateFollowupMessage
+
+/// Get a followup message sent for an interaction.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetFollowupMessage {
+    #[builder(setter(into))]
+    interaction: InteractionToken,
+    message_id: MessageId,
+}
+
+impl Request for GetFollowupMessage {
+    type Output = Message;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = self.interaction.message_path(self.message_id);
+            discord.get_unauthorized(path).await
+        })
+    }
+}
+
+impl GetFollowupMessage {
+    pub async fn send(self, discord: &Discord) -> Result<Message, Error> {
+        self.execute(discord).await
+    }
+}
+
 // TODO: EditFollowupMessage
 // TODO: DeleteFollowupMessage
 
@@ -342,16 +883,29 @@ pub struct GetGuildApplicationCommandPermissions {
     guild_id: GuildId,
 }
 
+impl Request for GetGuildApplicationCommandPermissions {
+    type Output = Vec<GuildApplicationCommandPermissions>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "applications/{}/guilds/{}/commands/permissions",
+                self.application_id, self.guild_id
+            );
+            discord.get(path).await
+        })
+    }
+}
+
 impl GetGuildApplicationCommandPermissions {
     pub async fn send(
         self,
         discord: &Discord,
     ) -> Result<Vec<GuildApplicationCommandPermissions>, Error> {
-        let path = format!(
-            "applications/{}/guilds/{}/commands/permissions",
-            self.application_id, self.guild_id
-        );
-        discord.get(path).await
+        self.execute(discord).await
     }
 }
 
@@ -363,16 +917,29 @@ pub struct GetApplicationCommandPermissions {
     command_id: ApplicationCommandId,
 }
 
+impl Request for GetApplicationCommandPermissions {
+    type Output = GuildApplicationCommandPermissions;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "applications/{}/guilds/{}/commands/{}/permissions",
+                self.application_id, self.guild_id, self.command_id,
+            );
+            discord.get(path).await
+        })
+    }
+}
+
 impl GetApplicationCommandPermissions {
     pub async fn send(
         self,
         discord: &Discord,
     ) -> Result<GuildApplicationCommandPermissions, Error> {
-        let path = format!(
-            "applications/{}/guilds/{}/commands/{}/permissions",
-            self.application_id, self.guild_id, self.command_id,
-        );
-        discord.get(path).await
+        self.execute(discord).await
     }
 }
 
@@ -387,29 +954,42 @@ pub struct EditApplicationCommandPermissions {
     permissions: Vec<ApplicationCommandPermission>,
 }
 
+impl Request for EditApplicationCommandPermissions {
+    type Output = GuildApplicationCommandPermissions;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "applications/{}/guilds/{}/commands/{}/permissions",
+                self.application_id, self.guild_id, self.command_id
+            );
+
+            #[derive(Debug, Serialize)]
+            struct Request<'a> {
+                permissions: &'a [ApplicationCommandPermission],
+            }
+
+            discord
+                .put(
+                    path,
+                    &Request {
+                        permissions: &self.permissions,
+                    },
+                )
+                .await
+        })
+    }
+}
+
 impl EditApplicationCommandPermissions {
     pub async fn send(
         self,
         discord: &Discord,
     ) -> Result<GuildApplicationCommandPermissions, Error> {
-        let path = format!(
-            "applications/{}/guilds/{}/commands/{}/permissions",
-            self.application_id, self.guild_id, self.command_id
-        );
-
-        #[derive(Debug, Serialize)]
-        struct Request<'a> {
-            permissions: &'a [ApplicationCommandPermission],
-        }
-
-        discord
-            .put(
-                path,
-                &Request {
-                    permissions: &self.permissions,
-                },
-            )
-            .await
+        self.execute(discord).await
     }
 }
 
@@ -423,17 +1003,30 @@ pub struct BatchEditApplicationCommandPermissions {
     command_permissions: Vec<EditGuildApplicationCommandPermissions>,
 }
 
+impl Request for BatchEditApplicationCommandPermissions {
+    type Output = Vec<GuildApplicationCommandPermissions>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "applications/{}/guilds/{}/commands/permissions",
+                self.application_id, self.guild_id
+            );
+
+            discord.put(path, &self.command_permissions).await
+        })
+    }
+}
+
 impl BatchEditApplicationCommandPermissions {
     pub async fn send(
         self,
         discord: &Discord,
     ) -> Result<Vec<GuildApplicationCommandPermissions>, Error> {
-        let path = format!(
-            "applications/{}/guilds/{}/commands/permissions",
-            self.application_id, self.guild_id
-        );
-
-        discord.put(path, &self.command_permissions).await
+        self.execute(discord).await
     }
 }
 
@@ -450,109 +1043,232 @@ pub struct GetGuildAuditLog {
     #[builder(default, setter(strip_option))]
     before: Option<AuditLogEntryId>,
 
+    #[builder(default, setter(strip_option))]
+    after: Option<AuditLogEntryId>,
+
     #[builder(default, setter(strip_option))]
     limit: Option<u64>,
 }
 
-impl GetGuildAuditLog {
-    pub async fn send(self, discord: &Discord) -> Result<AuditLog, Error> {
-        let mut path = format!("guilds/{}/audit-logs", self.guild_id);
-
-        let user_id = self.user_id.map(|u| format!("user_id={}", u));
-        let action_type = self
-            .action_kind
-            .map(|u| format!("action_type={}", u64::from(u)));
-        let before = self.before.map(|u| format!("before={}", u));
-        let limit = self.limit.map(|u| format!("limit={}", u));
-
-        let query = user_id
-            .into_iter()
-            .chain(action_type.into_iter())
-            .chain(before.into_iter())
-            .chain(limit.into_iter())
-            .collect::<Vec<_>>()
-            .join("&");
-
-        if !query.is_empty() {
-            path.push('?');
-            path.push_str(&query);
-        }
+#[derive(Debug, Serialize)]
+struct AuditLogQuery {
+    user_id: Option<UserId>,
+    action_type: Option<u64>,
+    before: Option<AuditLogEntryId>,
+    after: Option<AuditLogEntryId>,
+    limit: Option<u64>,
+}
+
+impl Request for GetGuildAuditLog {
+    type Output = AuditLog;
 
-        discord.get(path).await
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let query = AuditLogQuery {
+                user_id: self.user_id,
+                action_type: self.action_kind.map(u64::from),
+                before: self.before,
+                after: self.after,
+                limit: self.limit,
+            };
+
+            let path = format!("guilds/{}/audit-logs", self.guild_id);
+
+            discord.get_with_query(path, &query).await
+        })
     }
 }
 
-#[derive(Debug, Clone, TypedBuilder)]
-pub struct GetCurrentUser {
-    #[builder(default, setter(skip))]
-    _p: (),
+/// Pages backwards through the log, continuing `before` the oldest entry of
+/// each page, or forwards when started with `after`, continuing `after` the
+/// newest entry of each page.
+impl Paginate for GetGuildAuditLog {
+    type Item = AuditLogEntry;
+
+    fn items(page: AuditLog) -> Vec<AuditLogEntry> {
+        page.into_audit_log_entries()
+    }
+
+    fn page_size(&self) -> usize {
+        self.limit.unwrap_or(50) as usize
+    }
+
+    fn next_page(&self, page: &[AuditLogEntry]) -> Self {
+        let ids = page.iter().map(AuditLogEntry::id);
+
+        if self.after.is_some() {
+            Self {
+                after: ids.max(),
+                ..self.clone()
+            }
+        } else {
+            Self {
+                before: ids.min(),
+                ..self.clone()
+            }
+        }
+    }
 }
 
-impl GetCurrentUser {
-    pub async fn send(self, discord: &Discord) -> Result<User, Error> {
-        let path = "users/@me";
-        discord.get(path).await
+impl GetGuildAuditLog {
+    pub async fn send(self, discord: &Discord) -> Result<AuditLog, Error> {
+        self.execute(discord).await
     }
 }
 
 #[derive(Debug, Clone, TypedBuilder)]
-pub struct GetChannel {
-    channel_id: ChannelId,
+pub struct GetGuildPreview {
+    guild_id: GuildId,
 }
 
-impl GetChannel {
-    pub async fn send(self, discord: &Discord) -> Result<Channel, Error> {
-        let path = format!("channels/{}", self.channel_id);
-        discord.get(path).await
+impl Request for GetGuildPreview {
+    type Output = GuildPreview;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("guilds/{}/preview", self.guild_id);
+            discord.get(path).await
+        })
     }
 }
 
+impl GetGuildPreview {
+    pub async fn send(self, discord: &Discord) -> Result<GuildPreview, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Fetch a guild's widget as a PNG image.
 #[derive(Debug, Clone, TypedBuilder)]
-pub struct GetChannelMessage {
-    channel_id: ChannelId,
-    message_id: MessageId,
+pub struct GetGuildWidgetImage {
+    guild_id: GuildId,
+
+    #[builder(default, setter(strip_option))]
+    style: Option<WidgetStyle>,
 }
 
-impl GetChannelMessage {
-    pub async fn send(self, discord: &Discord) -> Result<Message, Error> {
-        let path = format!(
-            "channels/{}/messages/{}",
-            self.channel_id, self.message_id
-        );
-        discord.get(path).await
+impl Request for GetGuildWidgetImage {
+    type Output = Vec<u8>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let query = Query::new()
+                .push("style", self.style.as_ref().map(AsRef::<str>::as_ref));
+            let path = format!("guilds/{}/widget.png{}", self.guild_id, query);
+            discord.get_bytes(path).await
+        })
     }
 }
 
-#[derive(Debug, Clone, TypedBuilder, Serialize)]
-pub struct ModifyChannel {
-    channel_id: ChannelId,
+impl GetGuildWidgetImage {
+    pub async fn send(self, discord: &Discord) -> Result<Vec<u8>, Error> {
+        self.execute(discord).await
+    }
+}
 
-    #[builder(default, setter(strip_option, into))]
-    name: Option<String>,
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetGuildWelcomeScreen {
+    guild_id: GuildId,
+}
+
+impl Request for GetGuildWelcomeScreen {
+    type Output = WelcomeScreen;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("guilds/{}/welcome-screen", self.guild_id);
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetGuildWelcomeScreen {
+    pub async fn send(self, discord: &Discord) -> Result<WelcomeScreen, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ModifyGuildWelcomeScreen {
+    guild_id: GuildId,
 
     #[builder(default, setter(strip_option))]
-    icon: Option<UploadImage>,
+    enabled: Option<bool>,
 
     #[builder(default, setter(strip_option, into))]
-    kind: Option<IntegerEnum<ChannelKind>>,
+    description: Option<String>,
 
-    #[builder(default, setter(strip_option))]
-    position: Option<u64>,
+    #[builder(default, setter(strip_option, into))]
+    welcome_channels: Option<Vec<WelcomeScreenChannel>>,
+}
+
+impl Request for ModifyGuildWelcomeScreen {
+    type Output = WelcomeScreen;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("guilds/{}/welcome-screen", self.guild_id);
+
+            let body = EditWelcomeScreen {
+                enabled: self.enabled,
+                description: self.description.clone(),
+                welcome_channels: self.welcome_channels.clone(),
+            };
+
+            discord.patch(path, &body).await
+        })
+    }
+}
+
+impl ModifyGuildWelcomeScreen {
+    pub async fn send(self, discord: &Discord) -> Result<WelcomeScreen, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Create a new channel in a guild.
+///
+/// `rtc_region` and `video_quality_mode` only apply to voice and stage
+/// channels, and are left out of the request for every other kind.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct CreateGuildChannel {
+    guild_id: GuildId,
+
+    #[builder(setter(into))]
+    name: String,
+
+    #[builder(default, setter(strip_option, into))]
+    kind: Option<IntegerEnum<ChannelKind>>,
 
     #[builder(default, setter(strip_option, into))]
     topic: Option<String>,
 
     #[builder(default, setter(strip_option))]
-    nsfw: Option<bool>,
+    bitrate: Option<u64>,
 
     #[builder(default, setter(strip_option))]
-    rate_limit_per_user: Option<u64>,
+    user_limit: Option<u64>,
 
     #[builder(default, setter(strip_option))]
-    bitrate: Option<u64>,
+    rate_limit_per_user: Option<u64>,
 
     #[builder(default, setter(strip_option))]
-    user_limit: Option<u64>,
+    position: Option<u64>,
 
     #[builder(default, setter(strip_option, into))]
     permission_overwrites: Option<Vec<Overwrite>>,
@@ -560,45 +1276,4265 @@ pub struct ModifyChannel {
     #[builder(default, setter(strip_option))]
     parent_id: Option<ChannelId>,
 
+    #[builder(default, setter(strip_option))]
+    nsfw: Option<bool>,
+
     #[builder(default, setter(strip_option, into))]
     rtc_region: Option<String>,
 
     #[builder(default, setter(strip_option, into))]
     video_quality_mode: Option<IntegerEnum<VideoQualityMode>>,
+}
+
+impl Request for CreateGuildChannel {
+    type Output = Channel;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("guilds/{}/channels", self.guild_id);
+
+            let voice = self.is_voice();
+
+            let body = NewChannel {
+                name: self.name.clone(),
+                kind: self.kind,
+                topic: self.topic.clone(),
+                bitrate: self.bitrate,
+                user_limit: self.user_limit,
+                rate_limit_per_user: self.rate_limit_per_user,
+                position: self.position,
+                permission_overwrites: self.permission_overwrites.clone(),
+                parent_id: self.parent_id,
+                nsfw: self.nsfw,
+                rtc_region: self.rtc_region.clone().filter(|_| voice),
+                video_quality_mode: self.video_quality_mode.filter(|_| voice),
+            };
+
+            discord.post(path, &body).await
+        })
+    }
+}
+
+impl CreateGuildChannel {
+    fn is_voice(&self) -> bool {
+        matches!(
+            self.kind.map(IntegerEnum::try_unwrap),
+            Some(Ok(kind)) if kind.is_voice()
+        )
+    }
+
+    pub async fn send(self, discord: &Discord) -> Result<Channel, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Modify attributes of a guild member.
+///
+/// `nick`, `channel_id`, and `communication_disabled_until` take `None` to
+/// clear the field: removing the nickname, disconnecting the member from
+/// voice, or lifting a timeout, respectively.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ModifyGuildMember {
+    guild_id: GuildId,
+    user_id: UserId,
 
     #[builder(default, setter(strip_option))]
-    archived: Option<bool>,
+    nick: Option<Option<String>>,
+
+    #[builder(default, setter(strip_option, into))]
+    roles: Option<Vec<RoleId>>,
 
+    /// Server mute the member.
+    ///
+    /// Discord rejects this with [`Error::Discord`] if the member isn't
+    /// connected to a voice channel.
     #[builder(default, setter(strip_option))]
-    auto_archive_duration: Option<u64>,
+    mute: Option<bool>,
 
+    /// Server deafen the member.
+    ///
+    /// Discord rejects this with [`Error::Discord`] if the member isn't
+    /// connected to a voice channel.
     #[builder(default, setter(strip_option))]
-    locked: Option<bool>,
+    deaf: Option<bool>,
+
+    /// Move the member to another voice channel, or `None` to disconnect
+    /// them from voice.
+    #[builder(default, setter(strip_option))]
+    channel_id: Option<Option<ChannelId>>,
+
+    /// Time out the member until the given time, up to 28 days from now.
+    #[builder(default, setter(strip_option))]
+    communication_disabled_until: Option<Option<DateTime<FixedOffset>>>,
 }
 
-impl ModifyChannel {
-    pub async fn send(self, discord: &Discord) -> Result<Channel, Error> {
-        let path = format!("channels/{}", self.channel_id);
-
-        let body = EditChannel {
-            name: self.name,
-            icon: self.icon,
-            kind: self.kind,
-            position: self.position,
-            topic: self.topic,
-            nsfw: self.nsfw,
-            rate_limit_per_user: self.rate_limit_per_user,
-            bitrate: self.bitrate,
-            user_limit: self.user_limit,
-            permission_overwrites: self.permission_overwrites,
-            parent_id: self.parent_id,
-            rtc_region: self.rtc_region,
-            video_quality_mode: self.video_quality_mode,
-            archived: self.archived,
-            auto_archive_duration: self.auto_archive_duration,
-            locked: self.locked,
-        };
-
-        discord.patch(path, &body).await
+impl Request for ModifyGuildMember {
+    type Output = GuildMember;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path =
+                format!("guilds/{}/members/{}", self.guild_id, self.user_id);
+
+            let body = EditGuildMember {
+                nick: self.nick.clone(),
+                roles: self.roles.clone(),
+                mute: self.mute,
+                deaf: self.deaf,
+                channel_id: self.channel_id,
+                communication_disabled_until: self.communication_disabled_until,
+            };
+
+            discord.patch(path, &body).await
+        })
+    }
+}
+
+impl ModifyGuildMember {
+    pub async fn send(self, discord: &Discord) -> Result<GuildMember, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Change the current user's nickname in a guild.
+///
+/// Pass `None` to `nick` to remove the nickname.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ModifyCurrentUserNick {
+    guild_id: GuildId,
+
+    #[builder(setter(into))]
+    nick: Option<String>,
+}
+
+impl Request for ModifyCurrentUserNick {
+    type Output = GuildMember;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("guilds/{}/members/@me", self.guild_id);
+
+            let body = EditCurrentMember {
+                nick: Some(self.nick.clone()),
+            };
+
+            discord.patch(path, &body).await
+        })
+    }
+}
+
+impl ModifyCurrentUserNick {
+    pub async fn send(self, discord: &Discord) -> Result<GuildMember, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Add a user to a guild, using an OAuth2 access token granted by that user
+/// with the `guilds.join` scope.
+///
+/// Returns `None` if the user was already a member of the guild.
+#[derive(Educe, Clone, TypedBuilder)]
+#[educe(Debug)]
+pub struct AddGuildMember {
+    guild_id: GuildId,
+    user_id: UserId,
+
+    #[builder(setter(into))]
+    #[educe(Debug(method = "obscure"))]
+    access_token: String,
+
+    #[builder(default, setter(strip_option, into))]
+    nick: Option<String>,
+
+    #[builder(default, setter(strip_option, into))]
+    roles: Option<Vec<RoleId>>,
+
+    #[builder(default, setter(strip_option))]
+    mute: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    deaf: Option<bool>,
+}
+
+impl Request for AddGuildMember {
+    type Output = Option<GuildMember>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path =
+                format!("guilds/{}/members/{}", self.guild_id, self.user_id);
+
+            let body = NewGuildMember {
+                access_token: self.access_token.clone(),
+                nick: self.nick.clone(),
+                roles: self.roles.clone(),
+                mute: self.mute,
+                deaf: self.deaf,
+            };
+
+            discord.put_optional(path, &body).await
+        })
+    }
+}
+
+impl AddGuildMember {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<Option<GuildMember>, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ListScheduledEvents {
+    guild_id: GuildId,
+
+    #[builder(default, setter(strip_option))]
+    with_user_count: Option<bool>,
+}
+
+impl Request for ListScheduledEvents {
+    type Output = Vec<GuildScheduledEvent>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let query =
+                Query::new().push("with_user_count", self.with_user_count);
+
+            let path =
+                format!("guilds/{}/scheduled-events{}", self.guild_id, query);
+
+            discord.get(path).await
+        })
+    }
+}
+
+impl ListScheduledEvents {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<Vec<GuildScheduledEvent>, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct CreateScheduledEvent {
+    guild_id: GuildId,
+
+    #[builder(default, setter(strip_option))]
+    channel_id: Option<ChannelId>,
+
+    #[builder(default, setter(strip_option))]
+    entity_metadata: Option<EntityMetadata>,
+
+    #[builder(setter(into))]
+    name: String,
+
+    #[builder(
+        default_code = "ScheduledEventPrivacyLevel::GuildOnly.into()",
+        setter(into)
+    )]
+    privacy_level: IntegerEnum<ScheduledEventPrivacyLevel>,
+
+    scheduled_start_time: DateTime<FixedOffset>,
+
+    #[builder(default, setter(strip_option))]
+    scheduled_end_time: Option<DateTime<FixedOffset>>,
+
+    #[builder(default, setter(strip_option, into))]
+    description: Option<String>,
+
+    #[builder(setter(into))]
+    entity_kind: IntegerEnum<ScheduledEventEntityKind>,
+}
+
+impl Request for CreateScheduledEvent {
+    type Output = GuildScheduledEvent;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("guilds/{}/scheduled-events", self.guild_id);
+
+            let body = NewScheduledEvent {
+                channel_id: self.channel_id,
+                entity_metadata: self.entity_metadata.clone(),
+                name: self.name.clone(),
+                privacy_level: self.privacy_level,
+                scheduled_start_time: self.scheduled_start_time,
+                scheduled_end_time: self.scheduled_end_time,
+                description: self.description.clone(),
+                entity_kind: self.entity_kind,
+            };
+
+            discord.post(path, &body).await
+        })
+    }
+}
+
+impl CreateScheduledEvent {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<GuildScheduledEvent, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetScheduledEvent {
+    guild_id: GuildId,
+    guild_scheduled_event_id: GuildScheduledEventId,
+
+    #[builder(default, setter(strip_option))]
+    with_user_count: Option<bool>,
+}
+
+impl Request for GetScheduledEvent {
+    type Output = GuildScheduledEvent;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let query =
+                Query::new().push("with_user_count", self.with_user_count);
+
+            let path = format!(
+                "guilds/{}/scheduled-events/{}{}",
+                self.guild_id, self.guild_scheduled_event_id, query
+            );
+
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetScheduledEvent {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<GuildScheduledEvent, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ModifyScheduledEvent {
+    guild_id: GuildId,
+    guild_scheduled_event_id: GuildScheduledEventId,
+
+    /// Set to `None` when changing the event to an external one.
+    #[builder(default, setter(strip_option))]
+    channel_id: Option<Option<ChannelId>>,
+
+    #[builder(default, setter(strip_option))]
+    entity_metadata: Option<EntityMetadata>,
+
+    #[builder(default, setter(strip_option, into))]
+    name: Option<String>,
+
+    #[builder(default, setter(strip_option, into))]
+    privacy_level: Option<IntegerEnum<ScheduledEventPrivacyLevel>>,
+
+    #[builder(default, setter(strip_option))]
+    scheduled_start_time: Option<DateTime<FixedOffset>>,
+
+    #[builder(default, setter(strip_option))]
+    scheduled_end_time: Option<DateTime<FixedOffset>>,
+
+    #[builder(default, setter(strip_option, into))]
+    description: Option<String>,
+
+    #[builder(default, setter(strip_option, into))]
+    entity_kind: Option<IntegerEnum<ScheduledEventEntityKind>>,
+
+    #[builder(default, setter(strip_option, into))]
+    status: Option<IntegerEnum<ScheduledEventStatus>>,
+}
+
+impl Request for ModifyScheduledEvent {
+    type Output = GuildScheduledEvent;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "guilds/{}/scheduled-events/{}",
+                self.guild_id, self.guild_scheduled_event_id
+            );
+
+            let body = EditScheduledEvent {
+                channel_id: self.channel_id,
+                entity_metadata: self.entity_metadata.clone(),
+                name: self.name.clone(),
+                privacy_level: self.privacy_level,
+                scheduled_start_time: self.scheduled_start_time,
+                scheduled_end_time: self.scheduled_end_time,
+                description: self.description.clone(),
+                entity_kind: self.entity_kind,
+                status: self.status,
+            };
+
+            discord.patch(path, &body).await
+        })
+    }
+}
+
+impl ModifyScheduledEvent {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<GuildScheduledEvent, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct DeleteScheduledEvent {
+    guild_id: GuildId,
+    guild_scheduled_event_id: GuildScheduledEventId,
+}
+
+impl Request for DeleteScheduledEvent {
+    type Output = ();
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "guilds/{}/scheduled-events/{}",
+                self.guild_id, self.guild_scheduled_event_id
+            );
+            discord.delete(path).await
+        })
+    }
+}
+
+impl DeleteScheduledEvent {
+    pub async fn send(self, discord: &Discord) -> Result<(), Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetScheduledEventUsers {
+    guild_id: GuildId,
+    guild_scheduled_event_id: GuildScheduledEventId,
+
+    #[builder(default, setter(strip_option))]
+    limit: Option<u64>,
+
+    #[builder(default, setter(strip_option))]
+    with_member: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    before: Option<UserId>,
+
+    #[builder(default, setter(strip_option))]
+    after: Option<UserId>,
+}
+
+impl Request for GetScheduledEventUsers {
+    type Output = Vec<ScheduledEventUser>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let query = Query::new()
+                .push("limit", self.limit)
+                .push("with_member", self.with_member)
+                .push("before", self.before)
+                .push("after", self.after);
+
+            let path = format!(
+                "guilds/{}/scheduled-events/{}/users{}",
+                self.guild_id, self.guild_scheduled_event_id, query
+            );
+
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetScheduledEventUsers {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<Vec<ScheduledEventUser>, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ListAutoModerationRules {
+    guild_id: GuildId,
+}
+
+impl Request for ListAutoModerationRules {
+    type Output = Vec<AutoModerationRule>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path =
+                format!("guilds/{}/auto-moderation/rules", self.guild_id);
+            discord.get(path).await
+        })
+    }
+}
+
+impl ListAutoModerationRules {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<Vec<AutoModerationRule>, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetAutoModerationRule {
+    guild_id: GuildId,
+    rule_id: AutoModerationRuleId,
+}
+
+impl Request for GetAutoModerationRule {
+    type Output = AutoModerationRule;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "guilds/{}/auto-moderation/rules/{}",
+                self.guild_id, self.rule_id
+            );
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetAutoModerationRule {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<AutoModerationRule, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct CreateAutoModerationRule {
+    guild_id: GuildId,
+
+    #[builder(setter(into))]
+    name: String,
+
+    #[builder(
+        default_code = "AutoModerationEventKind::MessageSend.into()",
+        setter(into)
+    )]
+    event_kind: IntegerEnum<AutoModerationEventKind>,
+
+    #[builder(setter(into))]
+    trigger_kind: IntegerEnum<AutoModerationTriggerKind>,
+
+    /// Required for every trigger kind except
+    /// [`AutoModerationTriggerKind::Spam`].
+    #[builder(default, setter(strip_option))]
+    trigger_metadata: Option<TriggerMetadata>,
+
+    #[builder(setter(into))]
+    actions: Vec<AutoModerationAction>,
+
+    /// Rules are disabled unless this is set.
+    #[builder(default, setter(strip_option))]
+    enabled: Option<bool>,
+
+    #[builder(default, setter(strip_option, into))]
+    exempt_roles: Option<Vec<RoleId>>,
+
+    #[builder(default, setter(strip_option, into))]
+    exempt_channels: Option<Vec<ChannelId>>,
+}
+
+impl Request for CreateAutoModerationRule {
+    type Output = AutoModerationRule;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path =
+                format!("guilds/{}/auto-moderation/rules", self.guild_id);
+
+            let body = NewAutoModerationRule {
+                name: self.name.clone(),
+                event_kind: self.event_kind,
+                trigger_kind: self.trigger_kind,
+                trigger_metadata: self.trigger_metadata.clone(),
+                actions: self.actions.clone(),
+                enabled: self.enabled,
+                exempt_roles: self.exempt_roles.clone(),
+                exempt_channels: self.exempt_channels.clone(),
+            };
+
+            discord.post(path, &body).await
+        })
+    }
+}
+
+impl CreateAutoModerationRule {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<AutoModerationRule, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Change an auto-moderation rule. A rule's trigger kind can't be changed.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ModifyAutoModerationRule {
+    guild_id: GuildId,
+    rule_id: AutoModerationRuleId,
+
+    #[builder(default, setter(strip_option, into))]
+    name: Option<String>,
+
+    #[builder(default, setter(strip_option, into))]
+    event_kind: Option<IntegerEnum<AutoModerationEventKind>>,
+
+    #[builder(default, setter(strip_option))]
+    trigger_metadata: Option<TriggerMetadata>,
+
+    #[builder(default, setter(strip_option, into))]
+    actions: Option<Vec<AutoModerationAction>>,
+
+    #[builder(default, setter(strip_option))]
+    enabled: Option<bool>,
+
+    #[builder(default, setter(strip_option, into))]
+    exempt_roles: Option<Vec<RoleId>>,
+
+    #[builder(default, setter(strip_option, into))]
+    exempt_channels: Option<Vec<ChannelId>>,
+}
+
+impl Request for ModifyAutoModerationRule {
+    type Output = AutoModerationRule;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "guilds/{}/auto-moderation/rules/{}",
+                self.guild_id, self.rule_id
+            );
+
+            let body = EditAutoModerationRule {
+                name: self.name.clone(),
+                event_kind: self.event_kind,
+                trigger_metadata: self.trigger_metadata.clone(),
+                actions: self.actions.clone(),
+                enabled: self.enabled,
+                exempt_roles: self.exempt_roles.clone(),
+                exempt_channels: self.exempt_channels.clone(),
+            };
+
+            discord.patch(path, &body).await
+        })
+    }
+}
+
+impl ModifyAutoModerationRule {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<AutoModerationRule, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct DeleteAutoModerationRule {
+    guild_id: GuildId,
+    rule_id: AutoModerationRuleId,
+}
+
+impl Request for DeleteAutoModerationRule {
+    type Output = ();
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "guilds/{}/auto-moderation/rules/{}",
+                self.guild_id, self.rule_id
+            );
+            discord.delete(path).await
+        })
+    }
+}
+
+impl DeleteAutoModerationRule {
+    pub async fn send(self, discord: &Discord) -> Result<(), Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ModifyCurrentUserVoiceState {
+    guild_id: GuildId,
+
+    #[builder(default, setter(strip_option))]
+    channel_id: Option<ChannelId>,
+
+    #[builder(default, setter(strip_option))]
+    suppress: Option<bool>,
+
+    /// Set to `Some(timestamp)` to request to speak, or `None` to clear an
+    /// existing request.
+    #[builder(default, setter(strip_option))]
+    request_to_speak_timestamp: Option<Option<DateTime<FixedOffset>>>,
+}
+
+impl Request for ModifyCurrentUserVoiceState {
+    type Output = ();
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("guilds/{}/voice-states/@me", self.guild_id);
+
+            let body = EditVoiceState {
+                channel_id: self.channel_id,
+                suppress: self.suppress,
+                request_to_speak_timestamp: self.request_to_speak_timestamp,
+            };
+
+            discord.patch_no_content(path, &body).await
+        })
+    }
+}
+
+impl ModifyCurrentUserVoiceState {
+    pub async fn send(self, discord: &Discord) -> Result<(), Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ModifyUserVoiceState {
+    guild_id: GuildId,
+    user_id: UserId,
+
+    channel_id: ChannelId,
+
+    #[builder(default, setter(strip_option))]
+    suppress: Option<bool>,
+}
+
+impl Request for ModifyUserVoiceState {
+    type Output = ();
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "guilds/{}/voice-states/{}",
+                self.guild_id, self.user_id
+            );
+
+            let body = EditVoiceState {
+                channel_id: Some(self.channel_id),
+                suppress: self.suppress,
+                request_to_speak_timestamp: None,
+            };
+
+            discord.patch_no_content(path, &body).await
+        })
+    }
+}
+
+impl ModifyUserVoiceState {
+    pub async fn send(self, discord: &Discord) -> Result<(), Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetGuildVoiceRegions {
+    guild_id: GuildId,
+}
+
+impl Request for GetGuildVoiceRegions {
+    type Output = Vec<VoiceRegion>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("guilds/{}/regions", self.guild_id);
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetGuildVoiceRegions {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<Vec<VoiceRegion>, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ListVoiceRegions {
+    #[builder(default, setter(skip))]
+    _p: (),
+}
+
+impl Request for ListVoiceRegions {
+    type Output = Vec<VoiceRegion>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = "voice/regions";
+            discord.get(path).await
+        })
+    }
+}
+
+impl ListVoiceRegions {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<Vec<VoiceRegion>, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ListNitroStickerPacks {
+    #[builder(default, setter(skip))]
+    _p: (),
+}
+
+impl Request for ListNitroStickerPacks {
+    type Output = Vec<StickerPack>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = "sticker-packs";
+            let packs: StickerPacks = discord.get(path).await?;
+            Ok(packs.sticker_packs)
+        })
+    }
+}
+
+impl ListNitroStickerPacks {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<Vec<StickerPack>, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetSticker {
+    sticker_id: StickerId,
+}
+
+impl Request for GetSticker {
+    type Output = Sticker;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("stickers/{}", self.sticker_id);
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetSticker {
+    pub async fn send(self, discord: &Discord) -> Result<Sticker, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ListGuildStickers {
+    guild_id: GuildId,
+}
+
+impl Request for ListGuildStickers {
+    type Output = Vec<Sticker>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("guilds/{}/stickers", self.guild_id);
+            discord.get(path).await
+        })
+    }
+}
+
+impl ListGuildStickers {
+    pub async fn send(self, discord: &Discord) -> Result<Vec<Sticker>, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetGuildSticker {
+    guild_id: GuildId,
+    sticker_id: StickerId,
+}
+
+impl Request for GetGuildSticker {
+    type Output = Sticker;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "guilds/{}/stickers/{}",
+                self.guild_id, self.sticker_id
+            );
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetGuildSticker {
+    pub async fn send(self, discord: &Discord) -> Result<Sticker, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Upload a new sticker to a guild.
+///
+/// `file` holds the PNG, APNG, or Lottie JSON contents of the sticker, and
+/// `file_name` is the name reported to Discord for the upload.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct CreateGuildSticker {
+    guild_id: GuildId,
+
+    #[builder(setter(into))]
+    name: String,
+
+    #[builder(setter(into))]
+    description: String,
+
+    #[builder(setter(into))]
+    tags: String,
+
+    #[builder(setter(into))]
+    file_name: String,
+
+    #[builder(setter(into))]
+    file: Vec<u8>,
+
+    #[builder(default, setter(strip_option, into))]
+    content_type: Option<String>,
+}
+
+impl Request for CreateGuildSticker {
+    type Output = Sticker;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("guilds/{}/stickers", self.guild_id);
+
+            let mut file = Part::bytes(self.file.clone())
+                .file_name(self.file_name.clone());
+
+            if let Some(content_type) = &self.content_type {
+                file = file.mime_str(content_type)?;
+            }
+
+            let form = Form::new()
+                .text("name", self.name.clone())
+                .text("description", self.description.clone())
+                .text("tags", self.tags.clone())
+                .part("file", file);
+
+            discord.post_multipart(path, form).await
+        })
+    }
+}
+
+impl CreateGuildSticker {
+    pub async fn send(self, discord: &Discord) -> Result<Sticker, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ModifyGuildSticker {
+    guild_id: GuildId,
+    sticker_id: StickerId,
+
+    #[builder(default, setter(strip_option, into))]
+    name: Option<String>,
+
+    #[builder(default, setter(strip_option, into))]
+    description: Option<String>,
+
+    #[builder(default, setter(strip_option, into))]
+    tags: Option<String>,
+}
+
+impl Request for ModifyGuildSticker {
+    type Output = Sticker;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "guilds/{}/stickers/{}",
+                self.guild_id, self.sticker_id
+            );
+
+            let body = EditSticker {
+                name: self.name.clone(),
+                description: self.description.clone(),
+                tags: self.tags.clone(),
+            };
+
+            discord.patch(path, &body).await
+        })
+    }
+}
+
+impl ModifyGuildSticker {
+    pub async fn send(self, discord: &Discord) -> Result<Sticker, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct DeleteGuildSticker {
+    guild_id: GuildId,
+    sticker_id: StickerId,
+}
+
+impl Request for DeleteGuildSticker {
+    type Output = ();
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "guilds/{}/stickers/{}",
+                self.guild_id, self.sticker_id
+            );
+            discord.delete(path).await
+        })
+    }
+}
+
+impl DeleteGuildSticker {
+    pub async fn send(self, discord: &Discord) -> Result<(), Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ListGuildEmojis {
+    guild_id: GuildId,
+}
+
+impl Request for ListGuildEmojis {
+    type Output = Vec<Emoji>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("guilds/{}/emojis", self.guild_id);
+            discord.get(path).await
+        })
+    }
+}
+
+impl ListGuildEmojis {
+    pub async fn send(self, discord: &Discord) -> Result<Vec<Emoji>, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetGuildEmoji {
+    guild_id: GuildId,
+    emoji_id: EmojiId,
+}
+
+impl Request for GetGuildEmoji {
+    type Output = Emoji;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path =
+                format!("guilds/{}/emojis/{}", self.guild_id, self.emoji_id);
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetGuildEmoji {
+    pub async fn send(self, discord: &Discord) -> Result<Emoji, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Create a new custom emoji in a guild.
+///
+/// `roles` limits the emoji to members with any of those roles; leave it empty
+/// to allow everyone to use it.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct CreateGuildEmoji {
+    guild_id: GuildId,
+
+    #[builder(setter(into))]
+    name: String,
+
+    image: UploadImage,
+
+    #[builder(default, setter(into))]
+    roles: Vec<RoleId>,
+}
+
+impl Request for CreateGuildEmoji {
+    type Output = Emoji;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("guilds/{}/emojis", self.guild_id);
+
+            let body = NewEmoji {
+                name: self.name.clone(),
+                image: self.image.clone(),
+                roles: self.roles.clone(),
+            };
+
+            discord.post(path, &body).await
+        })
+    }
+}
+
+impl CreateGuildEmoji {
+    pub async fn send(self, discord: &Discord) -> Result<Emoji, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ModifyGuildEmoji {
+    guild_id: GuildId,
+    emoji_id: EmojiId,
+
+    #[builder(default, setter(strip_option, into))]
+    name: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    roles: Option<Option<Vec<RoleId>>>,
+}
+
+impl Request for ModifyGuildEmoji {
+    type Output = Emoji;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path =
+                format!("guilds/{}/emojis/{}", self.guild_id, self.emoji_id);
+
+            let body = EditEmoji {
+                name: self.name.clone(),
+                roles: self.roles.clone(),
+            };
+
+            discord.patch(path, &body).await
+        })
+    }
+}
+
+impl ModifyGuildEmoji {
+    pub async fn send(self, discord: &Discord) -> Result<Emoji, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct DeleteGuildEmoji {
+    guild_id: GuildId,
+    emoji_id: EmojiId,
+}
+
+impl Request for DeleteGuildEmoji {
+    type Output = ();
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path =
+                format!("guilds/{}/emojis/{}", self.guild_id, self.emoji_id);
+            discord.delete(path).await
+        })
+    }
+}
+
+impl DeleteGuildEmoji {
+    pub async fn send(self, discord: &Discord) -> Result<(), Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetCurrentUser {
+    #[builder(default, setter(skip))]
+    _p: (),
+}
+
+impl Request for GetCurrentUser {
+    type Output = User;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = "users/@me";
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetCurrentUser {
+    pub async fn send(self, discord: &Discord) -> Result<User, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Open a DM channel with a user, or get the one that's already open.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct CreateDm {
+    recipient_id: UserId,
+}
+
+impl Request for CreateDm {
+    type Output = Channel;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let body = NewDm {
+                recipient_id: self.recipient_id,
+            };
+
+            discord.post("users/@me/channels", &body).await
+        })
+    }
+}
+
+impl CreateDm {
+    pub async fn send(self, discord: &Discord) -> Result<Channel, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetUser {
+    user_id: UserId,
+}
+
+impl Request for GetUser {
+    type Output = User;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("users/{}", self.user_id);
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetUser {
+    pub async fn send(self, discord: &Discord) -> Result<User, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Get the current user's connected third-party accounts.
+///
+/// Requires a bearer token with the `connections` OAuth2 scope.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetUserConnections {
+    #[builder(default, setter(skip))]
+    _p: (),
+}
+
+impl Request for GetUserConnections {
+    type Output = Vec<Connection>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = "users/@me/connections";
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetUserConnections {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<Vec<Connection>, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetChannel {
+    channel_id: ChannelId,
+}
+
+impl Request for GetChannel {
+    type Output = Channel;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("channels/{}", self.channel_id);
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetChannel {
+    pub async fn send(self, discord: &Discord) -> Result<Channel, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetChannelMessage {
+    channel_id: ChannelId,
+    message_id: MessageId,
+}
+
+impl Request for GetChannelMessage {
+    type Output = Message;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "channels/{}/messages/{}",
+                self.channel_id, self.message_id
+            );
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetChannelMessage {
+    pub async fn send(self, discord: &Discord) -> Result<Message, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Post a message to a channel.
+///
+/// To make the send idempotent, set `nonce` (for example, to
+/// [`Nonce::snowflake`]) and `enforce_nonce`: Discord then returns the
+/// original message instead of posting a duplicate when the same nonce is sent
+/// again shortly after.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct CreateMessage {
+    channel_id: ChannelId,
+
+    #[builder(default, setter(strip_option, into))]
+    content: Option<String>,
+
+    #[builder(default, setter(strip_option, into))]
+    nonce: Option<Nonce>,
+
+    #[builder(default, setter(strip_option))]
+    enforce_nonce: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    tts: Option<bool>,
+
+    #[builder(default, setter(strip_option, into))]
+    embeds: Option<Vec<Embed>>,
+
+    #[builder(default, setter(strip_option))]
+    allowed_mentions: Option<AllowedMentions>,
+
+    #[builder(default, setter(strip_option))]
+    message_reference: Option<MessageReference>,
+
+    #[builder(default, setter(strip_option, into))]
+    sticker_ids: Option<Vec<StickerId>>,
+}
+
+impl Request for CreateMessage {
+    type Output = Message;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("channels/{}/messages", self.channel_id);
+
+            let body = NewMessage {
+                content: self.content.clone(),
+                nonce: self.nonce.clone(),
+                enforce_nonce: self.enforce_nonce,
+                tts: self.tts,
+                embeds: self.embeds.clone(),
+                allowed_mentions: self.allowed_mentions.clone(),
+                message_reference: self.message_reference.clone(),
+                sticker_ids: self.sticker_ids.clone(),
+            };
+
+            discord.post(path, &body).await
+        })
+    }
+}
+
+impl CreateMessage {
+    pub async fn send(self, discord: &Discord) -> Result<Message, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// List the archived public threads in a channel, most recently archived
+/// first.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ListPublicArchivedThreads {
+    channel_id: ChannelId,
+
+    /// Only return threads archived before this time.
+    #[builder(default, setter(strip_option))]
+    before: Option<DateTime<FixedOffset>>,
+
+    #[builder(default, setter(strip_option))]
+    limit: Option<u64>,
+}
+
+impl Request for ListPublicArchivedThreads {
+    type Output = ThreadList;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let query = Query::new()
+                .push("before", self.before.as_ref().map(query::timestamp))
+                .push("limit", self.limit);
+
+            let path = format!(
+                "channels/{}/threads/archived/public{}",
+                self.channel_id, query
+            );
+
+            discord.get(path).await
+        })
+    }
+}
+
+impl ListPublicArchivedThreads {
+    pub async fn send(self, discord: &Discord) -> Result<ThreadList, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// The id of `channel`, or a validation error if it isn't a thread.
+fn thread_id(channel: &Channel) -> Result<ChannelId, Error> {
+    if channel.is_thread() {
+        Ok(channel.id())
+    } else {
+        error::Validation {
+            field: "channel_id",
+            message: format!("channel {} is not a thread", channel.id()),
+        }
+        .fail()
+    }
+}
+
+/// Add the current user to a thread.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct JoinThread {
+    channel_id: ChannelId,
+}
+
+impl JoinThread {
+    /// Join `channel`, failing without contacting Discord if it isn't a
+    /// thread.
+    pub fn for_channel(channel: &Channel) -> Result<Self, Error> {
+        Ok(Self {
+            channel_id: thread_id(channel)?,
+        })
+    }
+
+    pub async fn send(self, discord: &Discord) -> Result<(), Error> {
+        self.execute(discord).await
+    }
+}
+
+impl Request for JoinThread {
+    type Output = ();
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path =
+                format!("channels/{}/thread-members/@me", self.channel_id);
+            discord.put_empty(path).await
+        })
+    }
+}
+
+/// Remove the current user from a thread.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct LeaveThread {
+    channel_id: ChannelId,
+}
+
+impl LeaveThread {
+    /// Leave `channel`, failing without contacting Discord if it isn't a
+    /// thread.
+    pub fn for_channel(channel: &Channel) -> Result<Self, Error> {
+        Ok(Self {
+            channel_id: thread_id(channel)?,
+        })
+    }
+
+    pub async fn send(self, discord: &Discord) -> Result<(), Error> {
+        self.execute(discord).await
+    }
+}
+
+impl Request for LeaveThread {
+    type Output = ();
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path =
+                format!("channels/{}/thread-members/@me", self.channel_id);
+            discord.delete(path).await
+        })
+    }
+}
+
+/// Add another member to a thread.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct AddThreadMember {
+    channel_id: ChannelId,
+    user_id: UserId,
+}
+
+impl AddThreadMember {
+    /// Add `user_id` to `channel`, failing without contacting Discord if it
+    /// isn't a thread.
+    pub fn for_channel(
+        channel: &Channel,
+        user_id: UserId,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            channel_id: thread_id(channel)?,
+            user_id,
+        })
+    }
+
+    pub async fn send(self, discord: &Discord) -> Result<(), Error> {
+        self.execute(discord).await
+    }
+}
+
+impl Request for AddThreadMember {
+    type Output = ();
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "channels/{}/thread-members/{}",
+                self.channel_id, self.user_id
+            );
+            discord.put_empty(path).await
+        })
+    }
+}
+
+/// Remove another member from a thread.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct RemoveThreadMember {
+    channel_id: ChannelId,
+    user_id: UserId,
+}
+
+impl RemoveThreadMember {
+    /// Remove `user_id` from `channel`, failing without contacting Discord
+    /// if it isn't a thread.
+    pub fn for_channel(
+        channel: &Channel,
+        user_id: UserId,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            channel_id: thread_id(channel)?,
+            user_id,
+        })
+    }
+
+    pub async fn send(self, discord: &Discord) -> Result<(), Error> {
+        self.execute(discord).await
+    }
+}
+
+impl Request for RemoveThreadMember {
+    type Output = ();
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "channels/{}/thread-members/{}",
+                self.channel_id, self.user_id
+            );
+            discord.delete(path).await
+        })
+    }
+}
+
+/// Publish a message in a news channel so it is sent to all following
+/// channels.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct CrosspostMessage {
+    channel_id: ChannelId,
+    message_id: MessageId,
+}
+
+impl Request for CrosspostMessage {
+    type Output = Message;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!(
+                "channels/{}/messages/{}/crosspost",
+                self.channel_id, self.message_id
+            );
+            discord.post_empty(path).await
+        })
+    }
+}
+
+impl CrosspostMessage {
+    pub async fn send(self, discord: &Discord) -> Result<Message, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// List the users who reacted to a message with an emoji.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetReactions {
+    channel_id: ChannelId,
+    message_id: MessageId,
+
+    /// The emoji in reaction form; see [`Emoji::reaction_form`].
+    #[builder(setter(into))]
+    emoji: String,
+
+    #[builder(default, setter(strip_option, into))]
+    after: Option<UserId>,
+
+    /// Users per page, at most 100. Discord defaults to 25.
+    #[builder(default, setter(strip_option))]
+    limit: Option<u64>,
+}
+
+impl GetReactions {
+    const MAX_LIMIT: u64 = 100;
+
+    pub async fn send(self, discord: &Discord) -> Result<Vec<User>, Error> {
+        self.execute(discord).await
+    }
+}
+
+impl Request for GetReactions {
+    type Output = Vec<User>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            if let Some(limit) = self.limit {
+                if limit > Self::MAX_LIMIT {
+                    return error::Validation {
+                        field: "limit",
+                        message: format!(
+                            "must be at most {}, not {}",
+                            Self::MAX_LIMIT,
+                            limit
+                        ),
+                    }
+                    .fail();
+                }
+            }
+
+            let query = Query::new()
+                .push("after", self.after)
+                .push("limit", self.limit);
+
+            let path = format!(
+                "channels/{}/messages/{}/reactions/{}{}",
+                self.channel_id, self.message_id, self.emoji, query
+            );
+
+            discord.get(path).await
+        })
+    }
+}
+
+/// Pages through reactors in id order, continuing `after` the last user of
+/// each page.
+impl Paginate for GetReactions {
+    type Item = User;
+
+    fn items(page: Vec<User>) -> Vec<User> {
+        page
+    }
+
+    fn page_size(&self) -> usize {
+        self.limit.unwrap_or(25) as usize
+    }
+
+    fn next_page(&self, page: &[User]) -> Self {
+        Self {
+            after: page.last().map(User::id),
+            ..self.clone()
+        }
+    }
+}
+
+/// Follow a news channel, sending its published messages to
+/// `webhook_channel_id`.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct FollowNewsChannel {
+    channel_id: ChannelId,
+    webhook_channel_id: ChannelId,
+}
+
+impl Request for FollowNewsChannel {
+    type Output = FollowedChannel;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("channels/{}/followers", self.channel_id);
+
+            let body = NewChannelFollower {
+                webhook_channel_id: self.webhook_channel_id,
+            };
+
+            discord.post(path, &body).await
+        })
+    }
+}
+
+impl FollowNewsChannel {
+    pub async fn send(
+        self,
+        discord: &Discord,
+    ) -> Result<FollowedChannel, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Get messages from a channel, newest first.
+///
+/// At most one of `around`, `before`, and `after` may be set.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetChannelMessages {
+    channel_id: ChannelId,
+
+    #[builder(default, setter(strip_option))]
+    around: Option<MessageId>,
+
+    #[builder(default, setter(strip_option))]
+    before: Option<MessageId>,
+
+    #[builder(default, setter(strip_option))]
+    after: Option<MessageId>,
+
+    #[builder(default, setter(strip_option))]
+    limit: Option<u64>,
+}
+
+impl Request for GetChannelMessages {
+    type Output = Vec<Message>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let query = Query::new()
+                .push("around", self.around)
+                .push("before", self.before)
+                .push("after", self.after)
+                .push("limit", self.limit);
+
+            let path =
+                format!("channels/{}/messages{}", self.channel_id, query);
+
+            discord.get(path).await
+        })
+    }
+}
+
+/// Pages backwards through history, continuing `before` the oldest message
+/// of each page, or forwards when started with `after`, continuing `after`
+/// the newest message of each page.
+///
+/// Pages started with `around` continue backwards from the oldest message
+/// of the first page.
+impl Paginate for GetChannelMessages {
+    type Item = Message;
+
+    fn items(page: Vec<Message>) -> Vec<Message> {
+        page
+    }
+
+    fn page_size(&self) -> usize {
+        self.limit.unwrap_or(50) as usize
+    }
+
+    fn next_page(&self, page: &[Message]) -> Self {
+        let ids = page.iter().map(Message::id);
+
+        if self.after.is_some() {
+            // Discord still lists each page newest first, so the newest
+            // message isn't necessarily the last one.
+            Self {
+                after: ids.max(),
+                ..self.clone()
+            }
+        } else {
+            Self {
+                around: None,
+                before: ids.min(),
+                ..self.clone()
+            }
+        }
+    }
+}
+
+impl GetChannelMessages {
+    pub async fn send(self, discord: &Discord) -> Result<Vec<Message>, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder, Serialize)]
+pub struct ModifyChannel {
+    channel_id: ChannelId,
+
+    #[builder(default, setter(strip_option, into))]
+    name: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    icon: Option<UploadImage>,
+
+    #[builder(default, setter(strip_option, into))]
+    kind: Option<IntegerEnum<ChannelKind>>,
+
+    #[builder(default, setter(strip_option))]
+    position: Option<u64>,
+
+    #[builder(default, setter(strip_option, into))]
+    topic: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    nsfw: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    rate_limit_per_user: Option<u64>,
+
+    #[builder(default, setter(strip_option))]
+    bitrate: Option<u64>,
+
+    #[builder(default, setter(strip_option))]
+    user_limit: Option<u64>,
+
+    #[builder(default, setter(strip_option, into))]
+    permission_overwrites: Option<Vec<Overwrite>>,
+
+    #[builder(default, setter(strip_option))]
+    parent_id: Option<ChannelId>,
+
+    #[builder(default, setter(strip_option, into))]
+    rtc_region: Option<String>,
+
+    #[builder(default, setter(strip_option, into))]
+    video_quality_mode: Option<IntegerEnum<VideoQualityMode>>,
+
+    #[builder(default, setter(strip_option))]
+    archived: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    auto_archive_duration: Option<u64>,
+
+    #[builder(default, setter(strip_option))]
+    locked: Option<bool>,
+
+    /// Check field values against Discord's documented limits before
+    /// sending, failing with [`Error::Validation`] instead of making the
+    /// request.
+    #[builder(default)]
+    #[serde(skip)]
+    validate: bool,
+}
+
+impl ModifyChannel {
+    const BITRATE: RangeInclusive<u64> = 8_000..=384_000;
+    const AUTO_ARCHIVE_DURATIONS: [u64; 4] = [60, 1440, 4320, 10080];
+
+    fn check(
+        field: &'static str,
+        valid: bool,
+        message: impl FnOnce() -> String,
+    ) -> Result<(), Error> {
+        if valid {
+            Ok(())
+        } else {
+            error::Validation {
+                field,
+                message: message(),
+            }
+            .fail()
+        }
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        if let Some(name) = &self.name {
+            let len = name.chars().count();
+            Self::check("name", (1..=100).contains(&len), || {
+                format!("must be 1-100 characters, not {}", len)
+            })?;
+        }
+
+        if let Some(topic) = &self.topic {
+            let len = topic.chars().count();
+            Self::check("topic", len <= 1024, || {
+                format!("must be at most 1024 characters, not {}", len)
+            })?;
+        }
+
+        if let Some(rate) = self.rate_limit_per_user {
+            Self::check("rate_limit_per_user", rate <= 21600, || {
+                format!("must be at most 21600 seconds, not {}", rate)
+            })?;
+        }
+
+        if let Some(limit) = self.user_limit {
+            Self::check("user_limit", limit <= 99, || {
+                format!("must be at most 99, not {}", limit)
+            })?;
+        }
+
+        if let Some(bitrate) = self.bitrate {
+            Self::check("bitrate", Self::BITRATE.contains(&bitrate), || {
+                format!(
+                    "must be between {} and {}, not {}",
+                    Self::BITRATE.start(),
+                    Self::BITRATE.end(),
+                    bitrate
+                )
+            })?;
+        }
+
+        if let Some(duration) = self.auto_archive_duration {
+            let valid = Self::AUTO_ARCHIVE_DURATIONS.contains(&duration);
+            Self::check("auto_archive_duration", valid, || {
+                format!(
+                    "must be one of {:?}, not {}",
+                    Self::AUTO_ARCHIVE_DURATIONS,
+                    duration
+                )
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Request for ModifyChannel {
+    type Output = Channel;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            if self.validate {
+                self.validate()?;
+            }
+
+            let path = format!("channels/{}", self.channel_id);
+
+            let body = EditChannel {
+                name: self.name.clone(),
+                icon: self.icon.clone(),
+                kind: self.kind,
+                position: self.position,
+                topic: self.topic.clone(),
+                nsfw: self.nsfw,
+                rate_limit_per_user: self.rate_limit_per_user,
+                bitrate: self.bitrate,
+                user_limit: self.user_limit,
+                permission_overwrites: self.permission_overwrites.clone(),
+                parent_id: self.parent_id,
+                rtc_region: self.rtc_region.clone(),
+                video_quality_mode: self.video_quality_mode,
+                archived: self.archived,
+                auto_archive_duration: self.auto_archive_duration,
+                locked: self.locked,
+            };
+
+            discord.patch(path, &body).await
+        })
+    }
+}
+
+impl ModifyChannel {
+    pub async fn send(self, discord: &Discord) -> Result<Channel, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Modify a group DM, which only supports changing its name and icon.
+///
+/// Use [`ModifyChannel`] for guild channels and threads.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct ModifyGroupDm {
+    channel_id: ChannelId,
+
+    #[builder(default, setter(strip_option, into))]
+    name: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    icon: Option<UploadImage>,
+}
+
+impl Request for ModifyGroupDm {
+    type Output = Channel;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("channels/{}", self.channel_id);
+
+            let body = EditGroupDm {
+                name: self.name.clone(),
+                icon: self.icon.clone(),
+            };
+
+            discord.patch(path, &body).await
+        })
+    }
+}
+
+impl ModifyGroupDm {
+    pub async fn send(self, discord: &Discord) -> Result<Channel, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetInvite {
+    #[builder(setter(into))]
+    code: String,
+
+    /// Include approximate member and presence counts.
+    #[builder(default, setter(strip_option))]
+    with_counts: Option<bool>,
+
+    /// Include the expiration date.
+    #[builder(default, setter(strip_option))]
+    with_expiration: Option<bool>,
+
+    /// Include the scheduled event with this id, if the invite is for it.
+    #[builder(default, setter(strip_option, into))]
+    guild_scheduled_event_id: Option<GuildScheduledEventId>,
+}
+
+impl Request for GetInvite {
+    type Output = Invite;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let query = Query::new()
+                .push("with_counts", self.with_counts)
+                .push("with_expiration", self.with_expiration)
+                .push(
+                    "guild_scheduled_event_id",
+                    self.guild_scheduled_event_id,
+                );
+
+            let path = format!("invites/{}{}", self.code, query);
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetInvite {
+    pub async fn send(self, discord: &Discord) -> Result<Invite, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// List the webhooks in a channel.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetChannelWebhooks {
+    channel_id: ChannelId,
+}
+
+impl Request for GetChannelWebhooks {
+    type Output = Vec<Webhook>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("channels/{}/webhooks", self.channel_id);
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetChannelWebhooks {
+    pub async fn send(self, discord: &Discord) -> Result<Vec<Webhook>, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// List the webhooks in a guild.
+///
+/// Application webhooks in the list have no guild or channel.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct GetGuildWebhooks {
+    guild_id: GuildId,
+}
+
+impl Request for GetGuildWebhooks {
+    type Output = Vec<Webhook>;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = format!("guilds/{}/webhooks", self.guild_id);
+            discord.get(path).await
+        })
+    }
+}
+
+impl GetGuildWebhooks {
+    pub async fn send(self, discord: &Discord) -> Result<Vec<Webhook>, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Get a message previously sent by a webhook.
+///
+/// Authenticated by the webhook token, so the client's own token isn't sent.
+#[derive(Educe, Clone, TypedBuilder)]
+#[educe(Debug)]
+pub struct GetWebhookMessage {
+    webhook_id: WebhookId,
+
+    #[builder(setter(into))]
+    #[educe(Debug(method = "obscure"))]
+    token: String,
+
+    message_id: MessageId,
+
+    /// Required when the message is in a thread.
+    #[builder(default, setter(strip_option, into))]
+    thread_id: Option<ChannelId>,
+}
+
+impl Request for GetWebhookMessage {
+    type Output = Message;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let query = Query::new().push("thread_id", self.thread_id);
+            let path = format!(
+                "webhooks/{}/{}/messages/{}{}",
+                self.webhook_id, self.token, self.message_id, query
+            );
+
+            discord.get_unauthorized(path).await
+        })
+    }
+}
+
+impl GetWebhookMessage {
+    pub async fn send(self, discord: &Discord) -> Result<Message, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Edit a message previously sent by a webhook.
+///
+/// Authenticated by the webhook token, so the client's own token isn't sent.
+#[derive(Educe, Clone, TypedBuilder)]
+#[educe(Debug)]
+pub struct EditWebhookMessage {
+    webhook_id: WebhookId,
+
+    #[builder(setter(into))]
+    #[educe(Debug(method = "obscure"))]
+    token: String,
+
+    message_id: MessageId,
+
+    /// Required when the message is in a thread.
+    #[builder(default, setter(strip_option, into))]
+    thread_id: Option<ChannelId>,
+
+    #[builder(default, setter(strip_option))]
+    content: Option<Option<String>>,
+
+    #[builder(default, setter(strip_option))]
+    embeds: Option<Option<Vec<Embed>>>,
+
+    #[builder(default, setter(strip_option))]
+    allowed_mentions: Option<Option<AllowedMentions>>,
+
+    #[builder(default, setter(strip_option))]
+    components: Option<Option<Vec<Component>>>,
+
+    /// The existing attachments to keep. Any attachment not listed is
+    /// removed from the message.
+    #[builder(default, setter(strip_option, into))]
+    attachments: Option<Vec<ExistingAttachment>>,
+}
+
+impl Request for EditWebhookMessage {
+    type Output = Message;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let query = Query::new().push("thread_id", self.thread_id);
+            let path = format!(
+                "webhooks/{}/{}/messages/{}{}",
+                self.webhook_id, self.token, self.message_id, query
+            );
+
+            let body = EditMessage {
+                content: self.content.clone(),
+                embeds: self.embeds.clone(),
+                allowed_mentions: self.allowed_mentions.clone(),
+                components: self.components.clone(),
+                attachments: self.attachments.clone(),
+            };
+
+            discord.patch_unauthorized(path, &body).await
+        })
+    }
+}
+
+impl EditWebhookMessage {
+    pub async fn send(self, discord: &Discord) -> Result<Message, Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Delete a message previously sent by a webhook.
+///
+/// Authenticated by the webhook token, so the client's own token isn't sent.
+#[derive(Educe, Clone, TypedBuilder)]
+#[educe(Debug)]
+pub struct DeleteWebhookMessage {
+    webhook_id: WebhookId,
+
+    #[builder(setter(into))]
+    #[educe(Debug(method = "obscure"))]
+    token: String,
+
+    message_id: MessageId,
+
+    /// Required when the message is in a thread.
+    #[builder(default, setter(strip_option, into))]
+    thread_id: Option<ChannelId>,
+}
+
+impl Request for DeleteWebhookMessage {
+    type Output = ();
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let query = Query::new().push("thread_id", self.thread_id);
+            let path = format!(
+                "webhooks/{}/{}/messages/{}{}",
+                self.webhook_id, self.token, self.message_id, query
+            );
+
+            discord.delete_unauthorized(path).await
+        })
+    }
+}
+
+impl DeleteWebhookMessage {
+    pub async fn send(self, discord: &Discord) -> Result<(), Error> {
+        self.execute(discord).await
+    }
+}
+
+/// Send a message through a webhook.
+///
+/// Authenticated by the webhook token, so the client's own token isn't sent.
+/// Discord doesn't return the message unless asked to; use
+/// [`ExecuteWebhook::wait`] to get it.
+#[derive(Educe, Clone, TypedBuilder)]
+#[educe(Debug)]
+pub struct ExecuteWebhook {
+    webhook_id: WebhookId,
+
+    #[builder(setter(into))]
+    #[educe(Debug(method = "obscure"))]
+    token: String,
+
+    /// Send the message to this thread in the webhook's channel.
+    #[builder(default, setter(strip_option, into))]
+    thread_id: Option<ChannelId>,
+
+    #[builder(default, setter(strip_option, into))]
+    content: Option<String>,
+
+    /// Override the webhook's default username.
+    #[builder(default, setter(strip_option, into))]
+    username: Option<String>,
+
+    /// Override the webhook's default avatar.
+    #[builder(default, setter(strip_option, into))]
+    avatar_url: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    tts: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    embeds: Option<Vec<Embed>>,
+
+    #[builder(default, setter(strip_option))]
+    allowed_mentions: Option<AllowedMentions>,
+
+    #[builder(default, setter(strip_option))]
+    components: Option<Vec<Component>>,
+}
+
+impl ExecuteWebhook {
+    /// Wait for Discord to create the message, and return it.
+    pub fn wait(self) -> ExecuteWebhookAndWait {
+        ExecuteWebhookAndWait { execute: self }
+    }
+
+    fn path(&self, wait: bool) -> String {
+        let query = Query::new()
+            .push("wait", Some(wait).filter(|w| *w))
+            .push("thread_id", self.thread_id);
+
+        format!("webhooks/{}/{}{}", self.webhook_id, self.token, query)
+    }
+
+    fn body(&self) -> NewWebhookMessage {
+        NewWebhookMessage {
+            content: self.content.clone(),
+            username: self.username.clone(),
+            avatar_url: self.avatar_url.clone(),
+            tts: self.tts,
+            embeds: self.embeds.clone(),
+            allowed_mentions: self.allowed_mentions.clone(),
+            components: self.components.clone(),
+        }
+    }
+
+    pub async fn send(self, discord: &Discord) -> Result<(), Error> {
+        self.execute(discord).await
+    }
+}
+
+impl Request for ExecuteWebhook {
+    type Output = ();
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = self.path(false);
+            discord
+                .post_unauthorized_no_content(path, &self.body())
+                .await
+        })
+    }
+}
+
+/// An [`ExecuteWebhook`] that returns the created message.
+#[derive(Debug, Clone)]
+pub struct ExecuteWebhookAndWait {
+    execute: ExecuteWebhook,
+}
+
+impl Request for ExecuteWebhookAndWait {
+    type Output = Message;
+
+    fn execute<'a>(
+        &'a self,
+        discord: &'a Discord,
+    ) -> BoxFuture<'a, Self::Output> {
+        Box::pin(async move {
+            let path = self.execute.path(true);
+            discord.post_unauthorized(path, &self.execute.body()).await
+        })
+    }
+}
+
+impl ExecuteWebhookAndWait {
+    pub async fn send(self, discord: &Discord) -> Result<Message, Error> {
+        self.execute(discord).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use crate::discord::paginate::Paginator;
+    use crate::mock::{MockResponse, MockServer};
+    use crate::permissions::Permissions;
+    use crate::resources::application::{
+        ApplicationCommandOptionKind, RoleConnectionMetadataKind,
+    };
+    use crate::resources::channel::MessageFlags;
+    use crate::resources::webhook::WebhookKind;
+
+    use futures_util::TryStreamExt;
+
+    use serde_json::json;
+
+    use super::*;
+
+    fn message_json(flags: u64) -> serde_json::Value {
+        json!({
+            "id": "334385199974967042",
+            "channel_id": "290926798999357250",
+            "content": "Big news!",
+            "timestamp": "2017-07-11T17:27:07.299000+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+            "flags": flags
+        })
+    }
+
+    /// A generic wrapper that only knows about the `Request` trait.
+    async fn send_twice<R>(
+        discord: &Discord,
+        request: &R,
+    ) -> Result<(R::Output, R::Output), Error>
+    where
+        R: Request,
+    {
+        let first = discord.request(request).await?;
+        let second = discord.request(request).await?;
+        Ok((first, second))
+    }
+
+    #[tokio::test]
+    async fn request_get_channel() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!({
+                "id": "41771983423143937",
+                "guild_id": "41771983429143937",
+                "name": "general",
+                "type": 0
+            }),
+        ));
+
+        let discord = server.discord();
+
+        let request = GetChannel::builder()
+            .channel_id(41771983423143937.into())
+            .build();
+
+        let (first, second) = send_twice(&discord, &request).await.unwrap();
+
+        assert_eq!(first.id(), 41771983423143937.into());
+        assert_eq!(second.name(), Some("general"));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+
+        for request in requests {
+            assert_eq!(request.method, "GET");
+            assert_eq!(request.path, "/channels/41771983423143937");
+        }
+    }
+
+    #[tokio::test]
+    async fn cloned_client_in_tasks() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!({
+                "id": "41771983423143937",
+                "type": 0
+            }),
+        ));
+
+        let discord = server.discord();
+
+        let tasks: Vec<_> = (0..2)
+            .map(|_| {
+                let discord = discord.clone();
+                tokio::spawn(async move {
+                    GetChannel::builder()
+                        .channel_id(41771983423143937.into())
+                        .build()
+                        .send(&discord)
+                        .await
+                })
+            })
+            .collect();
+
+        for task in tasks {
+            let channel = task.await.unwrap().unwrap();
+            assert_eq!(channel.id(), 41771983423143937.into());
+        }
+
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    fn access_token_json() -> serde_json::Value {
+        json!({
+            "access_token": "6qrZcUqja7812RVdnEKjpzOL4CvHBFG",
+            "token_type": "Bearer",
+            "expires_in": 604800,
+            "refresh_token": "D43f5y0ahjqew82jZ4NViEr2YafMKhue",
+            "scope": "identify"
+        })
+    }
+
+    #[tokio::test]
+    async fn token_exchange_authorization_code() {
+        let server =
+            MockServer::always(MockResponse::json(200, access_token_json()));
+
+        let token = TokenExchange::builder()
+            .client_id(172150183260323840)
+            .client_secret("s3cr3t")
+            .grant(Grant::AuthorizationCode {
+                code: "abc123".into(),
+                redirect_uri: "https://example.com/cb?x=1".into(),
+            })
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert_eq!(token.access_token(), "6qrZcUqja7812RVdnEKjpzOL4CvHBFG");
+
+        let request = server.only_request();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/oauth2/token");
+        assert_eq!(request.header("authorization"), None);
+        assert_eq!(
+            request.header("content-type"),
+            Some("application/x-www-form-urlencoded")
+        );
+        assert_eq!(
+            String::from_utf8(request.body).unwrap(),
+            "client_id=172150183260323840\
+             &client_secret=s3cr3t\
+             &grant_type=authorization_code\
+             &code=abc123\
+             &redirect_uri=https%3A%2F%2Fexample.com%2Fcb%3Fx%3D1"
+        );
+    }
+
+    #[tokio::test]
+    async fn token_exchange_refresh_token() {
+        let server =
+            MockServer::always(MockResponse::json(200, access_token_json()));
+
+        TokenExchange::builder()
+            .client_id(172150183260323840)
+            .client_secret("s3cr3t")
+            .grant(Grant::RefreshToken {
+                refresh_token: "D43f5y0ahjqew82jZ4NViEr2YafMKhue".into(),
+            })
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.header("authorization"), None);
+        assert_eq!(
+            String::from_utf8(request.body).unwrap(),
+            "client_id=172150183260323840\
+             &client_secret=s3cr3t\
+             &grant_type=refresh_token\
+             &refresh_token=D43f5y0ahjqew82jZ4NViEr2YafMKhue"
+        );
+    }
+
+    #[tokio::test]
+    async fn modify_channel_validate_name() {
+        let server = MockServer::always(MockResponse::json(200, json!({})));
+
+        let err = ModifyChannel::builder()
+            .channel_id(41771983423143937.into())
+            .name("x".repeat(101))
+            .validate(true)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap_err();
+
+        assert_matches!(err, Error::Validation { field: "name", .. });
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn modify_channel_validate_rate_limit() {
+        let server = MockServer::always(MockResponse::json(200, json!({})));
+
+        let err = ModifyChannel::builder()
+            .channel_id(41771983423143937.into())
+            .rate_limit_per_user(21601)
+            .validate(true)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap_err();
+
+        assert_matches!(
+            err,
+            Error::Validation {
+                field: "rate_limit_per_user",
+                ..
+            }
+        );
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn modify_channel_no_validation_by_default() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!({
+                "id": "41771983423143937",
+                "type": 0
+            }),
+        ));
+
+        ModifyChannel::builder()
+            .channel_id(41771983423143937.into())
+            .rate_limit_per_user(21601)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.json(), json!({"rate_limit_per_user": 21601}));
+    }
+
+    fn message_with_id(id: u64) -> serde_json::Value {
+        let mut json = message_json(0);
+        json["id"] = json!(id.to_string());
+        json
+    }
+
+    #[tokio::test]
+    async fn modify_group_dm() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!({
+                "id": "319674150115610528",
+                "type": 3,
+                "name": "Some test channel",
+                "icon": null,
+                "recipients": [],
+                "last_message_id": "3343820033257021450",
+                "owner_id": "82198810841029460"
+            }),
+        ));
+
+        let icon = UploadImage::builder()
+            .format(crate::image::Format::Png)
+            .data(vec![1, 2, 3])
+            .build();
+
+        let channel = ModifyGroupDm::builder()
+            .channel_id(319674150115610528.into())
+            .name("Some test channel")
+            .icon(icon)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert_eq!(channel.kind(), Some(ChannelKind::GroupDm));
+
+        let request = server.only_request();
+        assert_eq!(request.method, "PATCH");
+        assert_eq!(request.path, "/channels/319674150115610528");
+        assert_eq!(
+            request.json(),
+            json!({
+                "name": "Some test channel",
+                "icon": "data:image/png;base64,AQID"
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn get_invite_query() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!({ "code": "0vCdhLbwjZZTWZLD" }),
+        ));
+        let discord = server.discord();
+
+        GetInvite::builder()
+            .code("0vCdhLbwjZZTWZLD")
+            .build()
+            .send(&discord)
+            .await
+            .unwrap();
+
+        GetInvite::builder()
+            .code("0vCdhLbwjZZTWZLD")
+            .with_counts(true)
+            .with_expiration(false)
+            .guild_scheduled_event_id(941368096185204747)
+            .build()
+            .send(&discord)
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/invites/0vCdhLbwjZZTWZLD");
+        assert_eq!(
+            requests[1].path,
+            "/invites/0vCdhLbwjZZTWZLD?with_counts=true&with_expiration=false\
+             &guild_scheduled_event_id=941368096185204747"
+        );
+    }
+
+    fn sync_commands(description: &str) -> SyncGuildApplicationCommands {
+        let option = ApplicationCommandOption::builder()
+            .kind(ApplicationCommandOptionKind::String)
+            .name("text")
+            .description("What to say")
+            .required(true)
+            .build();
+
+        let command = NewApplicationCommand::builder()
+            .name("echo")
+            .description(description)
+            .options(vec![option])
+            .build();
+
+        SyncGuildApplicationCommands::builder()
+            .application_id(ApplicationId::from(775799577604522054))
+            .guild_id(290926798626357999.into())
+            .commands(vec![command])
+            .build()
+    }
+
+    fn registered_commands_json() -> serde_json::Value {
+        json!([{
+            "id": "775799577604522058",
+            "type": 1,
+            "application_id": "775799577604522054",
+            "name": "echo",
+            "description": "Repeat something",
+            "options": [{
+                "type": 3,
+                "name": "text",
+                "description": "What to say",
+                "required": true
+            }],
+            "default_permission": true
+        }])
+    }
+
+    #[tokio::test]
+    async fn update_role_connection_metadata() {
+        let server = MockServer::always(MockResponse::json(200, json!([])));
+
+        let record = RoleConnectionMetadata::builder()
+            .kind(RoleConnectionMetadataKind::BooleanEqual)
+            .key("verified")
+            .name("Verified")
+            .description("Has a verified account")
+            .build();
+
+        UpdateApplicationRoleConnectionMetadataRecords::builder()
+            .application_id(ApplicationId::from(775799577604522054))
+            .records(vec![record])
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.method, "PUT");
+        assert_eq!(
+            request.path,
+            "/applications/775799577604522054/role-connections/metadata"
+        );
+        assert_eq!(
+            request.json(),
+            json!([{
+                "type": 7,
+                "key": "verified",
+                "name": "Verified",
+                "description": "Has a verified account"
+            }])
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_commands_unchanged() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            registered_commands_json(),
+        ));
+
+        let commands = sync_commands("Repeat something")
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert_eq!(commands.len(), 1);
+
+        let request = server.only_request();
+        assert_eq!(request.method, "GET");
+        assert_eq!(
+            request.path,
+            "/applications/775799577604522054/guilds/290926798626357999/\
+             commands?with_localizations=true"
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_commands_localized_unchanged() {
+        let mut registered = registered_commands_json();
+        registered[0]["name_localizations"] = json!({ "de": "echo" });
+        registered[0]["description_localizations"] =
+            json!({ "de": "Etwas wiederholen" });
+
+        let server = MockServer::always(MockResponse::json(200, registered));
+
+        let mut names = HashMap::new();
+        names.insert("de".to_owned(), "echo".to_owned());
+
+        let mut descriptions = HashMap::new();
+        descriptions.insert("de".to_owned(), "Etwas wiederholen".to_owned());
+
+        let option = ApplicationCommandOption::builder()
+            .kind(ApplicationCommandOptionKind::String)
+            .name("text")
+            .description("What to say")
+            .required(true)
+            .build();
+
+        let command = NewApplicationCommand::builder()
+            .name("echo")
+            .name_localizations(names)
+            .description("Repeat something")
+            .description_localizations(descriptions)
+            .options(vec![option])
+            .build();
+
+        SyncGuildApplicationCommands::builder()
+            .application_id(ApplicationId::from(775799577604522054))
+            .guild_id(290926798626357999.into())
+            .commands(vec![command])
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.method, "GET");
+        assert!(request.path.ends_with("?with_localizations=true"));
+    }
+
+    #[tokio::test]
+    async fn sync_commands_changed() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            registered_commands_json(),
+        ));
+
+        sync_commands("Say something back")
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[1].method, "PUT");
+        assert_eq!(
+            requests[1].json()[0]["description"],
+            json!("Say something back")
+        );
+    }
+
+    #[tokio::test]
+    async fn get_original_interaction_response() {
+        let server =
+            MockServer::always(MockResponse::json(200, message_json(0)));
+
+        let token = InteractionToken::new(
+            ApplicationId::from(775799577604522054),
+            "aW50ZXJhY3Rpb24",
+        );
+        assert_eq!(token.application_id(), 775799577604522054.into());
+        assert_eq!(token.token(), "aW50ZXJhY3Rpb24");
+
+        GetOriginalInteractionResponse::builder()
+            .interaction(token)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.method, "GET");
+        assert_eq!(
+            request.path,
+            "/webhooks/775799577604522054/aW50ZXJhY3Rpb24/messages/@original"
+        );
+        assert_eq!(request.header("authorization"), None);
+    }
+
+    #[tokio::test]
+    async fn get_followup_message() {
+        let server =
+            MockServer::always(MockResponse::json(200, message_json(0)));
+
+        GetFollowupMessage::builder()
+            .interaction((
+                ApplicationId::from(775799577604522054),
+                "aW50ZXJhY3Rpb24",
+            ))
+            .message_id(334385199974967042.into())
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            server.only_request().path,
+            "/webhooks/775799577604522054/aW50ZXJhY3Rpb24/messages/\
+             334385199974967042"
+        );
+    }
+
+    #[tokio::test]
+    async fn get_webhook_message_without_auth() {
+        let server =
+            MockServer::always(MockResponse::json(200, message_json(0)));
+
+        let message = GetWebhookMessage::builder()
+            .webhook_id(223704706495545344.into())
+            .token("3d89bb7572e0fb30")
+            .message_id(334385199974967042.into())
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert_eq!(message.id(), 334385199974967042.into());
+
+        let request = server.only_request();
+        assert_eq!(request.method, "GET");
+        assert_eq!(
+            request.path,
+            "/webhooks/223704706495545344/3d89bb7572e0fb30/messages/\
+             334385199974967042"
+        );
+        assert_eq!(request.header("authorization"), None);
+    }
+
+    #[tokio::test]
+    async fn edit_webhook_message_without_auth() {
+        let server =
+            MockServer::always(MockResponse::json(200, message_json(0)));
+        let discord = server.discord();
+
+        EditWebhookMessage::builder()
+            .webhook_id(223704706495545344.into())
+            .token("3d89bb7572e0fb30")
+            .message_id(334385199974967042.into())
+            .content(Some("edited".to_owned()))
+            .embeds(None)
+            .build()
+            .send(&discord)
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.method, "PATCH");
+        assert_eq!(
+            request.path,
+            "/webhooks/223704706495545344/3d89bb7572e0fb30/messages/\
+             334385199974967042"
+        );
+        assert_eq!(request.header("authorization"), None);
+        assert_eq!(
+            request.json(),
+            json!({ "content": "edited", "embeds": null })
+        );
+    }
+
+    #[tokio::test]
+    async fn edit_webhook_message_retain_attachment() {
+        let server =
+            MockServer::always(MockResponse::json(200, message_json(0)));
+
+        // The message has attachments 1001 and 1002; only keep the first.
+        EditWebhookMessage::builder()
+            .webhook_id(223704706495545344.into())
+            .token("3d89bb7572e0fb30")
+            .message_id(334385199974967042.into())
+            .attachments(vec![ExistingAttachment::new(1001.into())])
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(
+            request.json(),
+            json!({ "attachments": [{ "id": "1001" }] })
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_webhook_message_without_auth() {
+        let server = MockServer::always(MockResponse::empty(204));
+
+        DeleteWebhookMessage::builder()
+            .webhook_id(223704706495545344.into())
+            .token("3d89bb7572e0fb30")
+            .message_id(334385199974967042.into())
+            .thread_id(41771983423143937)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.method, "DELETE");
+        assert_eq!(
+            request.path,
+            "/webhooks/223704706495545344/3d89bb7572e0fb30/messages/\
+             334385199974967042?thread_id=41771983423143937"
+        );
+        assert_eq!(request.header("authorization"), None);
+    }
+
+    #[tokio::test]
+    async fn get_guild_webhooks_mixed_kinds() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!([
+                {
+                    "type": 1,
+                    "id": "223704706495545344",
+                    "name": "test webhook",
+                    "avatar": null,
+                    "channel_id": "199737254929760256",
+                    "guild_id": "199737254929760256",
+                    "application_id": null,
+                    "token": "3d89bb7572e0fb30"
+                },
+                {
+                    "type": 2,
+                    "id": "752831914402115456",
+                    "name": "Guildy name",
+                    "avatar": null,
+                    "channel_id": "561885260615255432",
+                    "guild_id": "199737254929760256",
+                    "application_id": null,
+                    "source_guild": {
+                        "id": "56188498421476534",
+                        "name": "Guildy name",
+                        "icon": null
+                    }
+                },
+                {
+                    "type": 3,
+                    "id": "658822586720976555",
+                    "name": "Clyde",
+                    "avatar": null,
+                    "channel_id": null,
+                    "guild_id": null,
+                    "application_id": "658822586720976555"
+                }
+            ]),
+        ));
+
+        let webhooks = GetGuildWebhooks::builder()
+            .guild_id(199737254929760256.into())
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/guilds/199737254929760256/webhooks");
+
+        let kinds: Vec<_> = webhooks.iter().map(Webhook::kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                WebhookKind::Incoming,
+                WebhookKind::ChannelFollower,
+                WebhookKind::Application,
+            ]
+        );
+
+        assert_eq!(webhooks[0].token(), Some("3d89bb7572e0fb30"));
+        assert_eq!(
+            webhooks[1].source_guild().unwrap().name(),
+            Some("Guildy name")
+        );
+        assert_eq!(webhooks[2].guild_id(), None);
+        assert_eq!(webhooks[2].channel_id(), None);
+    }
+
+    #[tokio::test]
+    async fn get_channel_webhooks() {
+        let server = MockServer::always(MockResponse::json(200, json!([])));
+
+        let webhooks = GetChannelWebhooks::builder()
+            .channel_id(199737254929760256.into())
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert!(webhooks.is_empty());
+        assert_eq!(
+            server.only_request().path,
+            "/channels/199737254929760256/webhooks"
+        );
+    }
+
+    #[test]
+    fn webhook_requests_debug_hides_token() {
+        let token = "3d89bb7572e0fb30";
+
+        let execute = ExecuteWebhook::builder()
+            .webhook_id(223704706495545344.into())
+            .token(token)
+            .content("hello")
+            .build();
+
+        let edit = EditWebhookMessage::builder()
+            .webhook_id(223704706495545344.into())
+            .token(token)
+            .message_id(334385199974967042.into())
+            .build();
+
+        let get = GetWebhookMessage::builder()
+            .webhook_id(223704706495545344.into())
+            .token(token)
+            .message_id(334385199974967042.into())
+            .build();
+
+        let delete = DeleteWebhookMessage::builder()
+            .webhook_id(223704706495545344.into())
+            .token(token)
+            .message_id(334385199974967042.into())
+            .build();
+
+        let outputs = [
+            format!("{:?}", execute),
+            format!("{:?}", execute.wait()),
+            format!("{:?}", edit),
+            format!("{:?}", get),
+            format!("{:?}", delete),
+        ];
+
+        for output in &outputs {
+            assert!(!output.contains(token), "token leaked: {}", output);
+            assert!(output.contains("223704706495545344"));
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_webhook_without_wait() {
+        let server = MockServer::always(MockResponse::empty(204));
+
+        ExecuteWebhook::builder()
+            .webhook_id(223704706495545344.into())
+            .token("3d89bb7572e0fb30")
+            .content("hello")
+            .username("Captain Hook")
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.method, "POST");
+        assert_eq!(
+            request.path,
+            "/webhooks/223704706495545344/3d89bb7572e0fb30"
+        );
+        assert_eq!(request.header("authorization"), None);
+        assert_eq!(
+            request.json(),
+            json!({ "content": "hello", "username": "Captain Hook" })
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_webhook_and_wait() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            message_with_id(334385199974967042),
+        ));
+
+        let message = ExecuteWebhook::builder()
+            .webhook_id(223704706495545344.into())
+            .token("3d89bb7572e0fb30")
+            .thread_id(41771983423143937)
+            .content("hello")
+            .build()
+            .wait()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert_eq!(message.id(), 334385199974967042.into());
+
+        let request = server.only_request();
+        assert_eq!(
+            request.path,
+            "/webhooks/223704706495545344/3d89bb7572e0fb30\
+             ?wait=true&thread_id=41771983423143937"
+        );
+        assert_eq!(request.json(), json!({ "content": "hello" }));
+    }
+
+    #[tokio::test]
+    async fn modify_current_user_voice_state_without_channel() {
+        let server = MockServer::always(MockResponse::empty(204));
+
+        ModifyCurrentUserVoiceState::builder()
+            .guild_id(41771983429143937.into())
+            .suppress(false)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.method, "PATCH");
+        assert_eq!(request.path, "/guilds/41771983429143937/voice-states/@me");
+        assert_eq!(request.json(), json!({ "suppress": false }));
+    }
+
+    #[tokio::test]
+    async fn list_scheduled_events_with_user_count() {
+        let server = MockServer::always(MockResponse::json(200, json!([])));
+
+        let events = ListScheduledEvents::builder()
+            .guild_id(41771983429143937.into())
+            .with_user_count(true)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert!(events.is_empty());
+
+        let request = server.only_request();
+        assert_eq!(
+            request.path,
+            "/guilds/41771983429143937/scheduled-events?with_user_count=true"
+        );
+    }
+
+    #[tokio::test]
+    async fn requests_carry_authorization() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!([message_json(0)]),
+        ));
+
+        GetChannelMessages::builder()
+            .channel_id(290926798999357250.into())
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert!(request.header("authorization").is_some());
+    }
+
+    #[tokio::test]
+    async fn messages_all_pages() {
+        let server = MockServer::start(|request| {
+            let page = if request.path.contains("before=4") {
+                json!([message_with_id(3)])
+            } else {
+                json!([message_with_id(5), message_with_id(4)])
+            };
+            MockResponse::json(200, page)
+        });
+
+        let discord = server.discord();
+
+        let request = GetChannelMessages::builder()
+            .channel_id(290926798999357250.into())
+            .limit(2)
+            .build();
+
+        let ids: Vec<u64> = Paginator::new(&discord, request)
+            .all()
+            .map_ok(|m| u64::from(m.id()))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(ids, [5, 4, 3]);
+
+        let paths: Vec<_> =
+            server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths,
+            [
+                "/channels/290926798999357250/messages?limit=2",
+                "/channels/290926798999357250/messages?before=4&limit=2",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn messages_all_pages_after() {
+        let server = MockServer::start(|request| {
+            let page = if request.path.contains("after=3") {
+                json!([message_with_id(4)])
+            } else {
+                json!([message_with_id(3), message_with_id(2)])
+            };
+            MockResponse::json(200, page)
+        });
+
+        let discord = server.discord();
+
+        let request = GetChannelMessages::builder()
+            .channel_id(290926798999357250.into())
+            .after(1.into())
+            .limit(2)
+            .build();
+
+        let ids: Vec<u64> = Paginator::new(&discord, request)
+            .all()
+            .map_ok(|m| u64::from(m.id()))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(ids, [3, 2, 4]);
+
+        let paths: Vec<_> =
+            server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths,
+            [
+                "/channels/290926798999357250/messages?after=1&limit=2",
+                "/channels/290926798999357250/messages?after=3&limit=2",
+            ]
+        );
+    }
+
+    fn audit_log_with_ids(ids: &[u64]) -> serde_json::Value {
+        let entries: Vec<_> = ids
+            .iter()
+            .map(|id| {
+                json!({
+                    "id": id.to_string(),
+                    "action_type": 22,
+                    "target_id": "53908099506183680",
+                    "user_id": "144232857852837888"
+                })
+            })
+            .collect();
+
+        json!({
+            "webhooks": [],
+            "users": [],
+            "audit_log_entries": entries,
+            "integrations": []
+        })
+    }
+
+    #[tokio::test]
+    async fn audit_log_all_pages() {
+        let server = MockServer::start(|request| {
+            let page = if request.path.contains("before=4") {
+                audit_log_with_ids(&[3])
+            } else {
+                audit_log_with_ids(&[5, 4])
+            };
+            MockResponse::json(200, page)
+        });
+
+        let discord = server.discord();
+
+        let request = GetGuildAuditLog::builder()
+            .guild_id(41771983429143937.into())
+            .limit(2)
+            .build();
+
+        let ids: Vec<u64> = Paginator::new(&discord, request)
+            .all()
+            .map_ok(|e| u64::from(e.id()))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(ids, [5, 4, 3]);
+
+        let paths: Vec<_> =
+            server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths,
+            [
+                "/guilds/41771983429143937/audit-logs?limit=2",
+                "/guilds/41771983429143937/audit-logs?before=4&limit=2",
+            ]
+        );
+    }
+
+    fn user_with_id(id: u64) -> serde_json::Value {
+        json!({
+            "id": id.to_string(),
+            "username": "reactor",
+            "discriminator": "0001",
+            "avatar": null
+        })
+    }
+
+    #[tokio::test]
+    async fn reactions_all_pages() {
+        let server = MockServer::start(|request| {
+            let page = if request.path.contains("after=2") {
+                json!([user_with_id(3)])
+            } else {
+                json!([user_with_id(1), user_with_id(2)])
+            };
+            MockResponse::json(200, page)
+        });
+
+        let discord = server.discord();
+
+        let request = GetReactions::builder()
+            .channel_id(290926798999357250.into())
+            .message_id(334385199974967042.into())
+            .emoji("🔥")
+            .limit(2)
+            .build();
+
+        let ids: Vec<u64> = Paginator::new(&discord, request)
+            .all()
+            .map_ok(|u| u64::from(u.id()))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(ids, [1, 2, 3]);
+
+        let paths: Vec<_> =
+            server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths,
+            [
+                "/channels/290926798999357250/messages/334385199974967042/\
+                 reactions/%F0%9F%94%A5?limit=2",
+                "/channels/290926798999357250/messages/334385199974967042/\
+                 reactions/%F0%9F%94%A5?after=2&limit=2",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn get_reactions_limit_too_large() {
+        let server = MockServer::always(MockResponse::json(200, json!([])));
+
+        let err = GetReactions::builder()
+            .channel_id(290926798999357250.into())
+            .message_id(334385199974967042.into())
+            .emoji("LUL:41771983429993937")
+            .limit(101)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap_err();
+
+        assert_matches!(err, Error::Validation { field: "limit", .. });
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn create_guild_emoji() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!({
+                "id": "41771983429993937",
+                "name": "LUL",
+                "roles": [],
+                "require_colons": true,
+                "managed": false,
+                "animated": false,
+                "available": true
+            }),
+        ));
+
+        let image = UploadImage::builder()
+            .format(crate::image::Format::Png)
+            .data(vec![1, 2, 3])
+            .build();
+
+        let emoji = CreateGuildEmoji::builder()
+            .guild_id(290926798626357260.into())
+            .name("LUL")
+            .image(image)
+            .roles(vec![41771983423143936.into()])
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert_eq!(emoji.id(), Some(41771983429993937.into()));
+
+        let request = server.only_request();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/guilds/290926798626357260/emojis");
+        assert_eq!(
+            request.json(),
+            json!({
+                "name": "LUL",
+                "image": "data:image/png;base64,AQID",
+                "roles": ["41771983423143936"]
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn modify_guild_emoji_clear_roles() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!({ "id": "41771983429993937", "name": "LUL" }),
+        ));
+
+        ModifyGuildEmoji::builder()
+            .guild_id(290926798626357260.into())
+            .emoji_id(41771983429993937.into())
+            .roles(None)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.method, "PATCH");
+        assert_eq!(
+            request.path,
+            "/guilds/290926798626357260/emojis/41771983429993937"
+        );
+        assert_eq!(request.json(), json!({ "roles": null }));
+    }
+
+    #[tokio::test]
+    async fn create_message_with_nonce() {
+        let server =
+            MockServer::always(MockResponse::json(200, message_json(0)));
+
+        CreateMessage::builder()
+            .channel_id(290926798999357250.into())
+            .content("hello")
+            .nonce(Nonce::string("abc123"))
+            .enforce_nonce(true)
+            .message_reference(
+                MessageReference::builder()
+                    .message_id(334385199974967042.into())
+                    .build(),
+            )
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/channels/290926798999357250/messages");
+        assert_eq!(
+            request.json(),
+            json!({
+                "content": "hello",
+                "nonce": "abc123",
+                "enforce_nonce": true,
+                "message_reference": {
+                    "message_id": "334385199974967042"
+                }
+            })
+        );
+    }
+
+    fn member_json() -> serde_json::Value {
+        json!({
+            "roles": [],
+            "joined_at": "2015-04-26T06:26:56.936000+00:00",
+            "deaf": false,
+            "mute": false
+        })
+    }
+
+    fn channel_of_kind(kind: ChannelKind) -> Channel {
+        serde_json::from_value(json!({
+            "id": "41771983423143937",
+            "type": u64::from(kind),
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn join_thread() {
+        let server = MockServer::always(MockResponse::empty(204));
+        let thread = channel_of_kind(ChannelKind::GuildPublicThread);
+
+        JoinThread::for_channel(&thread)
+            .unwrap()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.method, "PUT");
+        assert_eq!(
+            request.path,
+            "/channels/41771983423143937/thread-members/@me"
+        );
+    }
+
+    #[test]
+    fn join_thread_rejects_text_channel() {
+        let channel = channel_of_kind(ChannelKind::GuildText);
+
+        let err = JoinThread::for_channel(&channel).unwrap_err();
+        assert_matches!(
+            err,
+            Error::Validation {
+                field: "channel_id",
+                ..
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn list_public_archived_threads_before() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!({ "threads": [], "members": [], "has_more": false }),
+        ));
+
+        let before =
+            DateTime::parse_from_rfc3339("2022-01-09T12:00:00-05:00").unwrap();
+
+        let list = ListPublicArchivedThreads::builder()
+            .channel_id(41771983423143937.into())
+            .before(before)
+            .limit(2)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert!(!list.has_more());
+
+        let request = server.only_request();
+        assert_eq!(
+            request.path,
+            "/channels/41771983423143937/threads/archived/public\
+             ?before=2022-01-09T12%3A00%3A00.000-05%3A00&limit=2"
+        );
+    }
+
+    #[tokio::test]
+    async fn modify_auto_moderation_rule_disable() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!({
+                "id": "969707018069872670",
+                "guild_id": "613425648685547541",
+                "name": "Keyword Filter 1",
+                "creator_id": "423457898095789043",
+                "trigger_type": 1,
+                "event_type": 1,
+                "actions": [{ "type": 1 }],
+                "trigger_metadata": { "keyword_filter": ["cat*"] },
+                "enabled": false,
+                "exempt_roles": [],
+                "exempt_channels": []
+            }),
+        ));
+
+        let rule = ModifyAutoModerationRule::builder()
+            .guild_id(613425648685547541.into())
+            .rule_id(969707018069872670.into())
+            .enabled(false)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert!(!rule.enabled());
+
+        let request = server.only_request();
+        assert_eq!(request.method, "PATCH");
+        assert_eq!(
+            request.path,
+            "/guilds/613425648685547541/auto-moderation/rules/\
+             969707018069872670"
+        );
+        assert_eq!(request.json(), json!({ "enabled": false }));
+    }
+
+    #[tokio::test]
+    async fn modify_guild_member_set_timeout() {
+        let server = MockServer::always(MockResponse::json(200, member_json()));
+
+        let until =
+            DateTime::parse_from_rfc3339("2022-01-09T12:00:00Z").unwrap();
+
+        ModifyGuildMember::builder()
+            .guild_id(290926798626357260.into())
+            .user_id(53908099506183680.into())
+            .communication_disabled_until(Some(until))
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.method, "PATCH");
+        assert_eq!(
+            request.path,
+            "/guilds/290926798626357260/members/53908099506183680"
+        );
+        assert_eq!(
+            request.json(),
+            json!({ "communication_disabled_until": "2022-01-09T12:00:00Z" })
+        );
+    }
+
+    #[tokio::test]
+    async fn modify_guild_member_clear_timeout() {
+        let server = MockServer::always(MockResponse::json(200, member_json()));
+
+        ModifyGuildMember::builder()
+            .guild_id(290926798626357260.into())
+            .user_id(53908099506183680.into())
+            .communication_disabled_until(None)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(
+            request.json(),
+            json!({ "communication_disabled_until": null })
+        );
+    }
+
+    #[tokio::test]
+    async fn modify_guild_member_disconnect_voice() {
+        let server = MockServer::always(MockResponse::json(200, member_json()));
+
+        ModifyGuildMember::builder()
+            .guild_id(290926798626357260.into())
+            .user_id(53908099506183680.into())
+            .mute(true)
+            .deaf(false)
+            .channel_id(None)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(
+            request.json(),
+            json!({ "mute": true, "deaf": false, "channel_id": null })
+        );
+    }
+
+    #[tokio::test]
+    async fn get_guild_audit_log_before_and_after() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!({
+                "webhooks": [],
+                "users": [],
+                "audit_log_entries": [],
+                "integrations": [],
+            }),
+        ));
+
+        GetGuildAuditLog::builder()
+            .guild_id(290926798626357260.into())
+            .before(840371689738715136.into())
+            .after(840371689738715100.into())
+            .limit(50)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.method, "GET");
+        assert_eq!(
+            request.path,
+            "/guilds/290926798626357260/audit-logs\
+             ?before=840371689738715136&after=840371689738715100&limit=50"
+        );
+    }
+
+    #[tokio::test]
+    async fn modify_current_user_nick_set() {
+        let server = MockServer::always(MockResponse::json(200, member_json()));
+
+        ModifyCurrentUserNick::builder()
+            .guild_id(290926798626357260.into())
+            .nick(Some("NOT API SUPPORT".to_owned()))
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.method, "PATCH");
+        assert_eq!(request.path, "/guilds/290926798626357260/members/@me");
+        assert_eq!(request.json(), json!({ "nick": "NOT API SUPPORT" }));
+    }
+
+    #[tokio::test]
+    async fn modify_current_user_nick_reset() {
+        let server = MockServer::always(MockResponse::json(200, member_json()));
+
+        ModifyCurrentUserNick::builder()
+            .guild_id(290926798626357260.into())
+            .nick(None)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.json(), json!({ "nick": null }));
+    }
+
+    #[tokio::test]
+    async fn add_guild_member() {
+        let server = MockServer::always(MockResponse::json(201, member_json()));
+
+        let member = AddGuildMember::builder()
+            .guild_id(290926798626357260.into())
+            .user_id(53908099506183680.into())
+            .access_token("6qrZcUqja7812RVdnEKjpzOL4CvHBFG")
+            .nick("Mase")
+            .roles(vec![41771983423143936.into()])
+            .mute(false)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert!(member.is_some());
+
+        let request = server.only_request();
+        assert_eq!(request.method, "PUT");
+        assert_eq!(
+            request.path,
+            "/guilds/290926798626357260/members/53908099506183680"
+        );
+        assert_eq!(
+            request.json(),
+            json!({
+                "access_token": "6qrZcUqja7812RVdnEKjpzOL4CvHBFG",
+                "nick": "Mase",
+                "roles": ["41771983423143936"],
+                "mute": false
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn add_guild_member_already_member() {
+        let server = MockServer::always(MockResponse::empty(204));
+
+        let request = AddGuildMember::builder()
+            .guild_id(290926798626357260.into())
+            .user_id(53908099506183680.into())
+            .access_token("6qrZcUqja7812RVdnEKjpzOL4CvHBFG")
+            .build();
+
+        assert!(!format!("{:?}", request).contains("6qrZcUqja"));
+
+        let member = request.send(&server.discord()).await.unwrap();
+        assert!(member.is_none());
+    }
+
+    #[tokio::test]
+    async fn get_guild_widget_image() {
+        let png = vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, 0xff];
+        let server = MockServer::always(MockResponse::bytes(
+            200,
+            "image/png",
+            png.clone(),
+        ));
+
+        let image = GetGuildWidgetImage::builder()
+            .guild_id(290926798626357260.into())
+            .style(WidgetStyle::Banner2)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert_eq!(image, png);
+
+        let request = server.only_request();
+        assert_eq!(request.method, "GET");
+        assert_eq!(
+            request.path,
+            "/guilds/290926798626357260/widget.png?style=banner2"
+        );
+    }
+
+    #[tokio::test]
+    async fn create_user_command() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!({
+                "id": "150",
+                "type": 2,
+                "application_id": "775799577604522054",
+                "name": "High Five",
+                "description": ""
+            }),
+        ));
+
+        let command = CreateGlobalApplicationCommand::builder()
+            .application_id(775799577604522054)
+            .kind(ApplicationCommandKind::User)
+            .name("High Five")
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert_eq!(command.kind(), ApplicationCommandKind::User);
+
+        let request = server.only_request();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/applications/775799577604522054/commands");
+
+        let body = request.json();
+        assert_eq!(body["type"], json!(2));
+        assert_eq!(body["description"], json!(""));
+    }
+
+    fn stage_channel_json() -> serde_json::Value {
+        json!({
+            "id": "41771983423143937",
+            "guild_id": "290926798626357260",
+            "name": "town hall",
+            "type": 13,
+            "rtc_region": "us-west",
+            "video_quality_mode": 2
+        })
+    }
+
+    #[tokio::test]
+    async fn create_guild_stage_channel() {
+        let server =
+            MockServer::always(MockResponse::json(200, stage_channel_json()));
+
+        let everyone = Overwrite::new(
+            RoleId::everyone(290926798626357260.into()).into(),
+            Permissions::empty(),
+            Permissions::SPEAK,
+        );
+
+        CreateGuildChannel::builder()
+            .guild_id(290926798626357260.into())
+            .name("town hall")
+            .kind(ChannelKind::GuildStageVoice)
+            .permission_overwrites(vec![everyone])
+            .rtc_region("us-west")
+            .video_quality_mode(VideoQualityMode::Full)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/guilds/290926798626357260/channels");
+        assert_eq!(
+            request.json(),
+            json!({
+                "name": "town hall",
+                "type": 13,
+                "permission_overwrites": [{
+                    "id": "290926798626357260",
+                    "type": 0,
+                    "allow": "0",
+                    "deny": "2097152"
+                }],
+                "rtc_region": "us-west",
+                "video_quality_mode": 2
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn create_guild_text_channel_omits_voice_fields() {
+        let server =
+            MockServer::always(MockResponse::json(200, stage_channel_json()));
+
+        CreateGuildChannel::builder()
+            .guild_id(290926798626357260.into())
+            .name("general")
+            .kind(ChannelKind::GuildText)
+            .rtc_region("us-west")
+            .video_quality_mode(VideoQualityMode::Full)
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        let request = server.only_request();
+        assert_eq!(request.json(), json!({ "name": "general", "type": 0 }));
+    }
+
+    #[tokio::test]
+    async fn crosspost_message() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            message_json(MessageFlags::CROSSPOSTED.bits()),
+        ));
+
+        let message = CrosspostMessage::builder()
+            .channel_id(290926798999357250.into())
+            .message_id(334385199974967042.into())
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert_eq!(message.flags(), Some(MessageFlags::CROSSPOSTED));
+
+        let request = server.only_request();
+        assert_eq!(request.method, "POST");
+        assert_eq!(
+            request.path,
+            "/channels/290926798999357250/messages/334385199974967042/crosspost"
+        );
+        assert!(request.body.is_empty());
+        assert_eq!(request.header("content-type"), None);
+    }
+
+    #[tokio::test]
+    async fn follow_news_channel() {
+        let server = MockServer::always(MockResponse::json(
+            200,
+            json!({
+                "channel_id": "290926798999357250",
+                "webhook_id": "290926798999357251"
+            }),
+        ));
+
+        let followed = FollowNewsChannel::builder()
+            .channel_id(290926798999357250.into())
+            .webhook_channel_id(41771983423143937.into())
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert_eq!(followed.channel_id(), 290926798999357250.into());
+        assert_eq!(followed.webhook_id(), 290926798999357251.into());
+
+        let request = server.only_request();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/channels/290926798999357250/followers");
+        assert_eq!(request.header("content-type"), Some("application/json"));
+        assert_eq!(
+            request.json(),
+            json!({"webhook_channel_id": "41771983423143937"})
+        );
     }
 }
@@ -44,7 +44,7 @@ impl ParseEnumError {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 enum Inner<T, R> {
     Parsed(T),
     Raw(R),
@@ -76,6 +76,28 @@ where
             Inner::Parsed(p) => Ok(*p),
         }
     }
+
+    /// The parsed value, or `None` if Discord sent a value this crate
+    /// doesn't know about.
+    pub fn known(&self) -> Option<T> {
+        match &self.0 {
+            Inner::Raw(_) => None,
+            Inner::Parsed(p) => Some(*p),
+        }
+    }
+}
+
+impl<T> StringEnum<T> {
+    /// Convert a known value with `f`, keeping an unknown value as-is.
+    pub fn map_known<U, F>(self, f: F) -> StringEnum<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self.0 {
+            Inner::Raw(raw) => StringEnum(Inner::Raw(raw)),
+            Inner::Parsed(p) => StringEnum(Inner::Parsed(f(p))),
+        }
+    }
 }
 
 impl<T> From<T> for StringEnum<T> {
@@ -146,7 +168,7 @@ where
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub struct IntegerEnum<T>(Inner<T, u64>);
 
 impl<T> IntegerEnum<T> {
@@ -175,6 +197,28 @@ where
             Inner::Parsed(p) => Ok(p),
         }
     }
+
+    /// The parsed value, or `None` if Discord sent a value this crate
+    /// doesn't know about.
+    pub fn known(&self) -> Option<T> {
+        match &self.0 {
+            Inner::Raw(_) => None,
+            Inner::Parsed(p) => Some(*p),
+        }
+    }
+}
+
+impl<T> IntegerEnum<T> {
+    /// Convert a known value with `f`, keeping an unknown value as-is.
+    pub fn map_known<U, F>(self, f: F) -> IntegerEnum<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self.0 {
+            Inner::Raw(raw) => IntegerEnum(Inner::Raw(raw)),
+            Inner::Parsed(p) => IntegerEnum(Inner::Parsed(f(p))),
+        }
+    }
 }
 
 impl<T> Serialize for IntegerEnum<T>
@@ -224,3 +268,57 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::resources::channel::ChannelKind;
+    use crate::resources::guild::GuildFeature;
+
+    use super::*;
+
+    fn features() -> Vec<StringEnum<GuildFeature>> {
+        serde_json::from_value(serde_json::json!([
+            "NEWS",
+            "SOME_FUTURE_FEATURE",
+            "COMMUNITY",
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn string_enum_known() {
+        let known: Vec<_> =
+            features().iter().filter_map(StringEnum::known).collect();
+
+        assert_eq!(known, [GuildFeature::News, GuildFeature::Community]);
+    }
+
+    #[test]
+    fn string_enum_map_known() {
+        let mapped: Vec<_> = features()
+            .into_iter()
+            .map(|f| f.map_known(|f| f == GuildFeature::News))
+            .collect();
+
+        assert_eq!(mapped[0].known(), Some(true));
+        assert_eq!(mapped[1].known(), None);
+        assert_eq!(mapped[1].to_string(), "SOME_FUTURE_FEATURE");
+        assert_eq!(mapped[2].known(), Some(false));
+    }
+
+    #[test]
+    fn integer_enum_known() {
+        let kinds: Vec<IntegerEnum<ChannelKind>> =
+            serde_json::from_str("[0, 999]").unwrap();
+
+        assert_eq!(kinds[0].known(), Some(ChannelKind::GuildText));
+        assert_eq!(kinds[1].known(), None);
+
+        let known: Vec<_> =
+            kinds.iter().filter_map(IntegerEnum::known).collect();
+        assert_eq!(known, [ChannelKind::GuildText]);
+
+        let unknown = kinds[1].map_known(u64::from);
+        assert_eq!(u64::from(unknown), 999);
+    }
+}
@@ -2,7 +2,809 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use serde::{Deserialize, Serialize};
+use crate::enums::{
+    EnumFromIntegerError, IntegerEnum, ParseEnumError, StringEnum,
+};
+use crate::resources::application::ApplicationId;
+use crate::resources::emoji::Emoji;
+use crate::resources::guild::{GuildId, GuildMember};
+use crate::resources::user::UserId;
 
+use serde::{Deserialize, Serialize, Serializer};
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use typed_builder::TypedBuilder;
+
+#[derive(Debug, Clone, Eq, Copy, PartialEq, Hash)]
+pub enum PresenceStatus {
+    Online,
+    Idle,
+    Dnd,
+    Offline,
+    Invisible,
+}
+
+impl FromStr for PresenceStatus {
+    type Err = ParseEnumError;
+
+    fn from_str(txt: &str) -> Result<Self, Self::Err> {
+        let r = match txt {
+            "online" => Self::Online,
+            "idle" => Self::Idle,
+            "dnd" => Self::Dnd,
+            "offline" => Self::Offline,
+            "invisible" => Self::Invisible,
+
+            other => return Err(ParseEnumError::new(other.to_owned())),
+        };
+
+        Ok(r)
+    }
+}
+
+impl AsRef<str> for PresenceStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Online => "online",
+            Self::Idle => "idle",
+            Self::Dnd => "dnd",
+            Self::Offline => "offline",
+            Self::Invisible => "invisible",
+        }
+    }
+}
+
+impl std::fmt::Display for PresenceStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let txt = self.as_ref();
+        f.write_str(txt)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ActivityKind {
+    Game,
+    Streaming,
+    Listening,
+    Watching,
+    Custom,
+    Competing,
+}
+
+impl TryFrom<u64> for ActivityKind {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            0 => Self::Game,
+            1 => Self::Streaming,
+            2 => Self::Listening,
+            3 => Self::Watching,
+            4 => Self::Custom,
+            5 => Self::Competing,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<ActivityKind> for u64 {
+    fn from(u: ActivityKind) -> Self {
+        match u {
+            ActivityKind::Game => 0,
+            ActivityKind::Streaming => 1,
+            ActivityKind::Listening => 2,
+            ActivityKind::Watching => 3,
+            ActivityKind::Custom => 4,
+            ActivityKind::Competing => 5,
+        }
+    }
+}
+
+/// Unix timestamps, in milliseconds, for the start and end of an activity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivityTimestamps {
+    start: Option<u64>,
+    end: Option<u64>,
+}
+
+impl ActivityTimestamps {
+    pub fn start(&self) -> Option<u64> {
+        self.start
+    }
+
+    pub fn end(&self) -> Option<u64> {
+        self.end
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivityParty {
+    id: Option<String>,
+    size: Option<[u64; 2]>,
+}
+
+impl ActivityParty {
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// The current and maximum size of the party.
+    pub fn size(&self) -> Option<[u64; 2]> {
+        self.size
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivityAssets {
+    large_image: Option<String>,
+    large_text: Option<String>,
+    small_image: Option<String>,
+    small_text: Option<String>,
+}
+
+impl ActivityAssets {
+    pub fn large_image(&self) -> Option<&str> {
+        self.large_image.as_deref()
+    }
+
+    pub fn large_text(&self) -> Option<&str> {
+        self.large_text.as_deref()
+    }
+
+    pub fn small_image(&self) -> Option<&str> {
+        self.small_image.as_deref()
+    }
+
+    pub fn small_text(&self) -> Option<&str> {
+        self.small_text.as_deref()
+    }
+}
+
+// Absent fields are skipped so an `Activity` can be sent in an
+// `UpdatePresence`, where bots may only set a few of them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Activity {
+    name: String,
+    #[serde(rename = "type")]
+    kind: IntegerEnum<ActivityKind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_at: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timestamps: Option<ActivityTimestamps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    application_id: Option<ApplicationId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji: Option<Emoji>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    party: Option<ActivityParty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assets: Option<ActivityAssets>,
+}
+
+impl Activity {
+    pub fn new<S>(kind: ActivityKind, name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            kind: kind.into(),
+            url: None,
+            created_at: None,
+            timestamps: None,
+            application_id: None,
+            details: None,
+            state: None,
+            emoji: None,
+            party: None,
+            assets: None,
+        }
+    }
+
+    /// Set the stream url, which Discord only shows for
+    /// [`ActivityKind::Streaming`].
+    pub fn with_url<S>(mut self, url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.url = Some(url.into());
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn try_kind(&self) -> Result<ActivityKind, EnumFromIntegerError> {
+        self.kind.try_unwrap()
+    }
+
+    pub fn kind(&self) -> ActivityKind {
+        self.kind.unwrap()
+    }
+
+    /// Stream url, only for [`ActivityKind::Streaming`].
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Unix timestamp, in milliseconds, of when the activity was added.
+    pub fn created_at(&self) -> Option<u64> {
+        self.created_at
+    }
+
+    pub fn timestamps(&self) -> Option<&ActivityTimestamps> {
+        self.timestamps.as_ref()
+    }
+
+    pub fn application_id(&self) -> Option<ApplicationId> {
+        self.application_id
+    }
+
+    pub fn details(&self) -> Option<&str> {
+        self.details.as_deref()
+    }
+
+    /// The party status, or for custom statuses, the text the user entered.
+    pub fn state(&self) -> Option<&str> {
+        self.state.as_deref()
+    }
+
+    /// The emoji of a custom status.
+    pub fn emoji(&self) -> Option<&Emoji> {
+        self.emoji.as_ref()
+    }
+
+    pub fn party(&self) -> Option<&ActivityParty> {
+        self.party.as_ref()
+    }
+
+    pub fn assets(&self) -> Option<&ActivityAssets> {
+        self.assets.as_ref()
+    }
+}
+
+/// The status a user has on each platform they're signed in to.
+///
+/// Platforms the user isn't active on are `None`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PresenceUpdateEvent {}
+pub struct ClientStatus {
+    desktop: Option<StringEnum<PresenceStatus>>,
+    mobile: Option<StringEnum<PresenceStatus>>,
+    web: Option<StringEnum<PresenceStatus>>,
+}
+
+impl ClientStatus {
+    pub fn try_desktop(
+        &self,
+    ) -> Option<Result<PresenceStatus, ParseEnumError>> {
+        self.desktop.as_ref().map(StringEnum::try_unwrap)
+    }
+
+    pub fn desktop(&self) -> Option<PresenceStatus> {
+        self.desktop.as_ref().map(StringEnum::unwrap)
+    }
+
+    pub fn try_mobile(&self) -> Option<Result<PresenceStatus, ParseEnumError>> {
+        self.mobile.as_ref().map(StringEnum::try_unwrap)
+    }
+
+    pub fn mobile(&self) -> Option<PresenceStatus> {
+        self.mobile.as_ref().map(StringEnum::unwrap)
+    }
+
+    pub fn try_web(&self) -> Option<Result<PresenceStatus, ParseEnumError>> {
+        self.web.as_ref().map(StringEnum::try_unwrap)
+    }
+
+    pub fn web(&self) -> Option<PresenceStatus> {
+        self.web.as_ref().map(StringEnum::unwrap)
+    }
+}
+
+/// The user a presence belongs to.
+///
+/// Discord only guarantees the `id`; the rest is sent when it changed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresenceUser {
+    id: UserId,
+    username: Option<String>,
+    discriminator: Option<String>,
+    avatar: Option<String>,
+    bot: Option<bool>,
+}
+
+impl PresenceUser {
+    pub fn id(&self) -> UserId {
+        self.id
+    }
+
+    pub fn username(&self) -> Option<&str> {
+        self.username.as_deref()
+    }
+
+    pub fn discriminator(&self) -> Option<&str> {
+        self.discriminator.as_deref()
+    }
+
+    pub fn avatar(&self) -> Option<&str> {
+        self.avatar.as_deref()
+    }
+
+    pub fn bot(&self) -> Option<bool> {
+        self.bot
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresenceUpdateEvent {
+    user: PresenceUser,
+    guild_id: Option<GuildId>,
+    status: StringEnum<PresenceStatus>,
+    activities: Vec<Activity>,
+    client_status: ClientStatus,
+}
+
+impl PresenceUpdateEvent {
+    pub fn user(&self) -> &PresenceUser {
+        &self.user
+    }
+
+    /// Absent when the presence is nested in a guild's `presences`.
+    pub fn guild_id(&self) -> Option<GuildId> {
+        self.guild_id
+    }
+
+    pub fn try_status(&self) -> Result<PresenceStatus, ParseEnumError> {
+        self.status.try_unwrap()
+    }
+
+    pub fn status(&self) -> PresenceStatus {
+        self.status.unwrap()
+    }
+
+    pub fn activities(&self) -> &[Activity] {
+        &self.activities
+    }
+
+    pub fn client_status(&self) -> &ClientStatus {
+        &self.client_status
+    }
+}
+
+#[derive(Serialize)]
+struct Command<'a, T> {
+    op: u64,
+    d: &'a T,
+}
+
+#[derive(Serialize)]
+struct PresenceData<'a> {
+    since: Option<u64>,
+    activities: &'a [Activity],
+    status: &'a StringEnum<PresenceStatus>,
+    afk: bool,
+}
+
+/// Set the bot's presence, sent as gateway opcode 3.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct UpdatePresence {
+    /// Unix timestamp, in milliseconds, of when the client went idle.
+    #[builder(default, setter(strip_option))]
+    since: Option<u64>,
+
+    #[builder(default, setter(into))]
+    activities: Vec<Activity>,
+
+    #[builder(default = PresenceStatus::Online.into(), setter(into))]
+    status: StringEnum<PresenceStatus>,
+
+    #[builder(default)]
+    afk: bool,
+}
+
+impl UpdatePresence {
+    /// Appear online with a single activity, like "Playing with Rust".
+    pub fn activity<S>(kind: ActivityKind, name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::builder()
+            .activities(vec![Activity::new(kind, name)])
+            .build()
+    }
+}
+
+impl Serialize for UpdatePresence {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let d = PresenceData {
+            since: self.since,
+            activities: &self.activities,
+            status: &self.status,
+            afk: self.afk,
+        };
+
+        Command { op: 3, d: &d }.serialize(s)
+    }
+}
+
+#[derive(Serialize)]
+struct GuildMembersData<'a> {
+    guild_id: GuildId,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query: Option<&'a str>,
+
+    limit: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    presences: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_ids: Option<&'a [UserId]>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nonce: Option<&'a str>,
+}
+
+/// Ask for the members of a guild, sent as gateway opcode 8.
+///
+/// Discord replies with one or more [`GuildMembersChunkEvent`]s, which a
+/// [`GuildMembersCollector`] can assemble. Set either `query` or `user_ids`.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct RequestGuildMembers {
+    guild_id: GuildId,
+
+    /// Username prefix to match, or an empty string for every member.
+    #[builder(default, setter(strip_option, into))]
+    query: Option<String>,
+
+    /// Maximum number of members to send, or zero for no limit when `query`
+    /// is empty.
+    #[builder(default)]
+    limit: u64,
+
+    #[builder(default, setter(strip_option))]
+    presences: Option<bool>,
+
+    #[builder(default, setter(strip_option, into))]
+    user_ids: Option<Vec<UserId>>,
+
+    /// Echoed back in each chunk, to tell responses apart.
+    #[builder(default, setter(strip_option, into))]
+    nonce: Option<String>,
+}
+
+impl Serialize for RequestGuildMembers {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let d = GuildMembersData {
+            guild_id: self.guild_id,
+            query: self.query.as_deref(),
+            limit: self.limit,
+            presences: self.presences,
+            user_ids: self.user_ids.as_deref(),
+            nonce: self.nonce.as_deref(),
+        };
+
+        Command { op: 8, d: &d }.serialize(s)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildMembersChunkEvent {
+    guild_id: GuildId,
+    members: Vec<GuildMember>,
+    chunk_index: u64,
+    chunk_count: u64,
+    not_found: Option<Vec<UserId>>,
+    presences: Option<Vec<PresenceUpdateEvent>>,
+    nonce: Option<String>,
+}
+
+impl GuildMembersChunkEvent {
+    pub fn guild_id(&self) -> GuildId {
+        self.guild_id
+    }
+
+    pub fn members(&self) -> &[GuildMember] {
+        &self.members
+    }
+
+    pub fn chunk_index(&self) -> u64 {
+        self.chunk_index
+    }
+
+    pub fn chunk_count(&self) -> u64 {
+        self.chunk_count
+    }
+
+    /// Requested user ids that aren't members of the guild.
+    pub fn not_found(&self) -> Option<&[UserId]> {
+        self.not_found.as_deref()
+    }
+
+    pub fn presences(&self) -> Option<&[PresenceUpdateEvent]> {
+        self.presences.as_deref()
+    }
+
+    pub fn nonce(&self) -> Option<&str> {
+        self.nonce.as_deref()
+    }
+}
+
+/// Gathers the chunks answering a [`RequestGuildMembers`].
+#[derive(Debug, Clone, Default)]
+pub struct GuildMembersCollector {
+    members: Vec<GuildMember>,
+    received: u64,
+    expected: Option<u64>,
+}
+
+impl GuildMembersCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a chunk, returning `true` once every chunk has arrived.
+    ///
+    /// Chunks may be pushed in any order.
+    pub fn push(&mut self, chunk: GuildMembersChunkEvent) -> bool {
+        self.expected = Some(chunk.chunk_count);
+        self.received += 1;
+        self.members.extend(chunk.members);
+        self.is_complete()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        matches!(self.expected, Some(e) if self.received >= e)
+    }
+
+    pub fn into_members(self) -> Vec<GuildMember> {
+        self.members
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_presence_with_custom_status() {
+        let json = json!({
+            "user": { "id": "80351110224678912" },
+            "guild_id": "41771983423143937",
+            "status": "dnd",
+            "activities": [
+                {
+                    "name": "Custom Status",
+                    "type": 4,
+                    "state": "Heads down",
+                    "emoji": { "name": "🎧" },
+                    "created_at": 1507665886000u64
+                }
+            ],
+            "client_status": { "desktop": "dnd", "mobile": "idle" }
+        });
+
+        let presence: PresenceUpdateEvent =
+            serde_json::from_value(json).unwrap();
+
+        assert_eq!(presence.user().id(), 80351110224678912.into());
+        assert_eq!(presence.user().username(), None);
+        assert_eq!(presence.guild_id(), Some(41771983423143937.into()));
+        assert_eq!(presence.status(), PresenceStatus::Dnd);
+
+        let activity = &presence.activities()[0];
+        assert_eq!(activity.name(), "Custom Status");
+        assert_eq!(activity.kind(), ActivityKind::Custom);
+        assert_eq!(activity.state(), Some("Heads down"));
+        assert_eq!(activity.emoji().unwrap().name(), Some("🎧"));
+
+        let client = presence.client_status();
+        assert_eq!(client.desktop(), Some(PresenceStatus::Dnd));
+        assert_eq!(client.mobile(), Some(PresenceStatus::Idle));
+        assert_eq!(client.web(), None);
+    }
+
+    #[test]
+    fn unknown_presence_status() {
+        let json = json!({
+            "user": { "id": "80351110224678912" },
+            "status": "away",
+            "activities": [],
+            "client_status": {}
+        });
+
+        let presence: PresenceUpdateEvent =
+            serde_json::from_value(json).unwrap();
+
+        assert_eq!(presence.try_status().unwrap_err().as_inner(), "away");
+    }
+
+    #[test]
+    fn deserialize_listening_activity() {
+        let json = json!({
+            "name": "Spotify",
+            "type": 2,
+            "created_at": 1641945310219u64,
+            "timestamps": { "start": 1641945308971u64, "end": 1641945523971u64 },
+            "details": "Never Gonna Give You Up",
+            "state": "Rick Astley",
+            "party": { "id": "spotify:80351110224678912" },
+            "assets": {
+                "large_image": "spotify:ab67616d0000b27315ebbedaacef61af244262a8",
+                "large_text": "Whenever You Need Somebody"
+            },
+            "sync_id": "4cOdK2wGLETKBW3PvgPWqT",
+            "session_id": "a37e1ec8d9f0d1b7d6d8fc7ec79dd86a",
+            "flags": 48
+        });
+
+        let activity: Activity = serde_json::from_value(json).unwrap();
+
+        assert_eq!(activity.name(), "Spotify");
+        assert_eq!(activity.kind(), ActivityKind::Listening);
+        assert_eq!(activity.url(), None);
+        assert_eq!(activity.details(), Some("Never Gonna Give You Up"));
+        assert_eq!(activity.state(), Some("Rick Astley"));
+
+        let timestamps = activity.timestamps().unwrap();
+        assert_eq!(timestamps.start(), Some(1641945308971));
+        assert_eq!(timestamps.end(), Some(1641945523971));
+
+        let party = activity.party().unwrap();
+        assert_eq!(party.id(), Some("spotify:80351110224678912"));
+        assert_eq!(party.size(), None);
+
+        let assets = activity.assets().unwrap();
+        assert_eq!(assets.large_text(), Some("Whenever You Need Somebody"));
+        assert_eq!(assets.small_image(), None);
+    }
+
+    #[test]
+    fn serialize_update_presence() {
+        let update = UpdatePresence::activity(ActivityKind::Game, "with Rust");
+
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({
+                "op": 3,
+                "d": {
+                    "since": null,
+                    "activities": [{ "name": "with Rust", "type": 0 }],
+                    "status": "online",
+                    "afk": false
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_update_presence_idle() {
+        let update = UpdatePresence::builder()
+            .since(91879201)
+            .activities(vec![Activity::new(ActivityKind::Streaming, "Rust")
+                .with_url("https://twitch.tv/discord")])
+            .status(PresenceStatus::Idle)
+            .afk(true)
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({
+                "op": 3,
+                "d": {
+                    "since": 91879201,
+                    "activities": [{
+                        "name": "Rust",
+                        "type": 1,
+                        "url": "https://twitch.tv/discord"
+                    }],
+                    "status": "idle",
+                    "afk": true
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_request_guild_members() {
+        let request = RequestGuildMembers::builder()
+            .guild_id(41771983423143937.into())
+            .query("")
+            .presences(true)
+            .nonce("abc")
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "op": 8,
+                "d": {
+                    "guild_id": "41771983423143937",
+                    "query": "",
+                    "limit": 0,
+                    "presences": true,
+                    "nonce": "abc"
+                }
+            })
+        );
+    }
+
+    fn members_chunk_json(index: u64) -> serde_json::Value {
+        json!({
+            "guild_id": "41771983423143937",
+            "members": [{
+                "user": {
+                    "id": "80351110224678912",
+                    "username": "Nelly",
+                    "discriminator": "1337",
+                    "avatar": null
+                },
+                "nick": null,
+                "roles": [],
+                "joined_at": "2015-04-26T06:26:56.936000+00:00",
+                "deaf": false,
+                "mute": false
+            }],
+            "chunk_index": index,
+            "chunk_count": 2,
+            "not_found": ["53908232506183680"],
+            "presences": [{
+                "user": { "id": "80351110224678912" },
+                "status": "online",
+                "activities": [],
+                "client_status": { "web": "online" }
+            }],
+            "nonce": "abc"
+        })
+    }
+
+    #[test]
+    fn deserialize_members_chunk() {
+        let chunk: GuildMembersChunkEvent =
+            serde_json::from_value(members_chunk_json(1)).unwrap();
+
+        assert_eq!(chunk.guild_id(), 41771983423143937.into());
+        assert_eq!(chunk.chunk_index(), 1);
+        assert_eq!(chunk.chunk_count(), 2);
+        assert_eq!(chunk.not_found(), Some(&[53908232506183680.into()][..]));
+        assert_eq!(chunk.nonce(), Some("abc"));
+        assert_eq!(chunk.members()[0].user().unwrap().username(), "Nelly");
+        assert_eq!(
+            chunk.presences().unwrap()[0].status(),
+            PresenceStatus::Online
+        );
+    }
+
+    #[test]
+    fn collect_members_chunks() {
+        let mut collector = GuildMembersCollector::new();
+
+        for index in (0..2).rev() {
+            let chunk = serde_json::from_value(members_chunk_json(index));
+            let done = collector.push(chunk.unwrap());
+            assert_eq!(done, index == 0);
+        }
+
+        assert_eq!(collector.into_members().len(), 2);
+    }
+}
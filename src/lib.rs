@@ -10,11 +10,21 @@ pub mod enums;
 pub mod game_sdk;
 pub mod gateway;
 pub mod image;
+pub mod locale;
+pub mod mention;
+#[cfg(test)]
+mod mock;
+pub mod oauth2;
 pub mod permissions;
 pub mod resources;
+#[cfg(test)]
+mod round_trip;
 pub mod snowflake;
 mod str;
 pub mod teams;
 mod visitor;
 
-pub use self::discord::{requests, Config, Discord, Error, Token};
+pub use self::discord::{
+    paginate, requests, ClientBuilderHook, Config, Discord, Error, FieldError,
+    ResponseHook, Token,
+};
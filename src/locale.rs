@@ -0,0 +1,192 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Languages supported by the Discord client.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// A locale, like `en-US`, as used by Discord.
+///
+/// Locales Discord adds after this enum was written parse as
+/// [`Locale::Other`], so parsing never fails.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Locale {
+    Indonesian,
+    Danish,
+    German,
+    EnglishUk,
+    EnglishUs,
+    Spanish,
+    SpanishLatam,
+    French,
+    Croatian,
+    Italian,
+    Lithuanian,
+    Hungarian,
+    Dutch,
+    Norwegian,
+    Polish,
+    PortugueseBrazil,
+    Romanian,
+    Finnish,
+    Swedish,
+    Vietnamese,
+    Turkish,
+    Czech,
+    Greek,
+    Bulgarian,
+    Russian,
+    Ukrainian,
+    Hindi,
+    Thai,
+    ChineseChina,
+    Japanese,
+    ChineseTaiwan,
+    Korean,
+    Other(String),
+}
+
+impl From<&str> for Locale {
+    fn from(s: &str) -> Self {
+        match s {
+            "id" => Self::Indonesian,
+            "da" => Self::Danish,
+            "de" => Self::German,
+            "en-GB" => Self::EnglishUk,
+            "en-US" => Self::EnglishUs,
+            "es-ES" => Self::Spanish,
+            "es-419" => Self::SpanishLatam,
+            "fr" => Self::French,
+            "hr" => Self::Croatian,
+            "it" => Self::Italian,
+            "lt" => Self::Lithuanian,
+            "hu" => Self::Hungarian,
+            "nl" => Self::Dutch,
+            "no" => Self::Norwegian,
+            "pl" => Self::Polish,
+            "pt-BR" => Self::PortugueseBrazil,
+            "ro" => Self::Romanian,
+            "fi" => Self::Finnish,
+            "sv-SE" => Self::Swedish,
+            "vi" => Self::Vietnamese,
+            "tr" => Self::Turkish,
+            "cs" => Self::Czech,
+            "el" => Self::Greek,
+            "bg" => Self::Bulgarian,
+            "ru" => Self::Russian,
+            "uk" => Self::Ukrainian,
+            "hi" => Self::Hindi,
+            "th" => Self::Thai,
+            "zh-CN" => Self::ChineseChina,
+            "ja" => Self::Japanese,
+            "zh-TW" => Self::ChineseTaiwan,
+            "ko" => Self::Korean,
+
+            other => Self::Other(other.to_owned()),
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl AsRef<str> for Locale {
+    fn as_ref(&self) -> &str {
+        match self {
+            Locale::Indonesian => "id",
+            Locale::Danish => "da",
+            Locale::German => "de",
+            Locale::EnglishUk => "en-GB",
+            Locale::EnglishUs => "en-US",
+            Locale::Spanish => "es-ES",
+            Locale::SpanishLatam => "es-419",
+            Locale::French => "fr",
+            Locale::Croatian => "hr",
+            Locale::Italian => "it",
+            Locale::Lithuanian => "lt",
+            Locale::Hungarian => "hu",
+            Locale::Dutch => "nl",
+            Locale::Norwegian => "no",
+            Locale::Polish => "pl",
+            Locale::PortugueseBrazil => "pt-BR",
+            Locale::Romanian => "ro",
+            Locale::Finnish => "fi",
+            Locale::Swedish => "sv-SE",
+            Locale::Vietnamese => "vi",
+            Locale::Turkish => "tr",
+            Locale::Czech => "cs",
+            Locale::Greek => "el",
+            Locale::Bulgarian => "bg",
+            Locale::Russian => "ru",
+            Locale::Ukrainian => "uk",
+            Locale::Hindi => "hi",
+            Locale::Thai => "th",
+            Locale::ChineseChina => "zh-CN",
+            Locale::Japanese => "ja",
+            Locale::ChineseTaiwan => "zh-TW",
+            Locale::Korean => "ko",
+            Locale::Other(other) => other,
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_ref())
+    }
+}
+
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let txt = String::deserialize(deserializer)?;
+        Ok(Self::from(txt.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_en_us() {
+        let locale: Locale = "en-US".parse().unwrap();
+        assert_eq!(locale, Locale::EnglishUs);
+        assert_eq!(locale.as_ref(), "en-US");
+    }
+
+    #[test]
+    fn parse_unknown() {
+        let locale: Locale = "tlh".parse().unwrap();
+        assert_eq!(locale, Locale::Other("tlh".to_owned()));
+        assert_eq!(locale.to_string(), "tlh");
+    }
+
+    #[test]
+    fn round_trip_json() {
+        let locale: Locale = serde_json::from_str("\"es-419\"").unwrap();
+        assert_eq!(locale, Locale::SpanishLatam);
+        assert_eq!(serde_json::to_string(&locale).unwrap(), "\"es-419\"");
+    }
+}
@@ -0,0 +1,262 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Parsing and formatting the mention syntax used in message content.
+//!
+//! ```
+//! use discord2::mention::{self, Mention};
+//!
+//! let user = mention::parse_user_mention("<@!80351110224678912>").unwrap();
+//! assert_eq!(Mention::User(user).to_string(), "<@80351110224678912>");
+//! ```
+
+use crate::permissions::RoleId;
+use crate::resources::channel::ChannelId;
+use crate::resources::emoji::EmojiId;
+use crate::resources::user::UserId;
+use crate::snowflake::Id;
+
+use std::fmt::{self, Display};
+
+/// A custom emoji as it appears in message content, like `<:name:id>` or
+/// `<a:name:id>` when animated.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct CustomEmoji<'a> {
+    animated: bool,
+    name: &'a str,
+    id: EmojiId,
+}
+
+impl<'a> CustomEmoji<'a> {
+    pub fn new(animated: bool, name: &'a str, id: EmojiId) -> Self {
+        Self { animated, name, id }
+    }
+
+    pub fn animated(&self) -> bool {
+        self.animated
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    pub fn id(&self) -> EmojiId {
+        self.id
+    }
+}
+
+impl Display for CustomEmoji<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let prefix = if self.animated { "a" } else { "" };
+        write!(f, "<{}:{}:{}>", prefix, self.name, self.id)
+    }
+}
+
+/// Any of the mentions Discord renders in message content.
+///
+/// Formatting a `Mention` produces the text Discord expects, and
+/// [`Mention::parse`] reverses it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Mention<'a> {
+    User(UserId),
+    Channel(ChannelId),
+    Role(RoleId),
+    Emoji(CustomEmoji<'a>),
+}
+
+impl<'a> Mention<'a> {
+    /// Parse a single mention, which must make up the whole of `text`.
+    pub fn parse(text: &'a str) -> Option<Self> {
+        parse_user_mention(text)
+            .map(Self::User)
+            .or_else(|| parse_channel_mention(text).map(Self::Channel))
+            .or_else(|| parse_role_mention(text).map(Self::Role))
+            .or_else(|| parse_custom_emoji(text).map(Self::Emoji))
+    }
+}
+
+impl Display for Mention<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::User(id) => write!(f, "<@{}>", id),
+            Self::Channel(id) => write!(f, "<#{}>", id),
+            Self::Role(id) => write!(f, "<@&{}>", id),
+            Self::Emoji(emoji) => Display::fmt(emoji, f),
+        }
+    }
+}
+
+impl UserId {
+    /// The mention for this user, which displays as `<@id>`.
+    pub fn mention(self) -> Mention<'static> {
+        Mention::User(self)
+    }
+}
+
+impl ChannelId {
+    /// The mention for this channel, which displays as `<#id>`.
+    pub fn mention(self) -> Mention<'static> {
+        Mention::Channel(self)
+    }
+}
+
+impl RoleId {
+    /// The mention for this role, which displays as `<@&id>`.
+    pub fn mention(self) -> Mention<'static> {
+        Mention::Role(self)
+    }
+}
+
+fn parse_id<T>(text: &str) -> Option<Id<T>> {
+    if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    text.parse::<u64>().ok().map(Id::from)
+}
+
+fn strip<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    text.strip_prefix(prefix)?.strip_suffix('>')
+}
+
+/// Parse `<@id>`, or the older nickname form `<@!id>`.
+pub fn parse_user_mention(text: &str) -> Option<UserId> {
+    let inner = strip(text, "<@")?;
+    parse_id(inner.strip_prefix('!').unwrap_or(inner))
+}
+
+/// Parse `<#id>`.
+pub fn parse_channel_mention(text: &str) -> Option<ChannelId> {
+    parse_id(strip(text, "<#")?)
+}
+
+/// Parse `<@&id>`.
+pub fn parse_role_mention(text: &str) -> Option<RoleId> {
+    parse_id(strip(text, "<@&")?)
+}
+
+/// Parse `<:name:id>` or, for animated emoji, `<a:name:id>`.
+pub fn parse_custom_emoji(text: &str) -> Option<CustomEmoji<'_>> {
+    let inner = strip(text, "<")?;
+
+    let (animated, rest) = match inner.strip_prefix("a:") {
+        Some(rest) => (true, rest),
+        None => (false, inner.strip_prefix(':')?),
+    };
+
+    let (name, id) = rest.split_at(rest.find(':')?);
+
+    if name.is_empty() {
+        return None;
+    }
+
+    Some(CustomEmoji::new(animated, name, parse_id(&id[1..])?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_mention() {
+        assert_eq!(
+            parse_user_mention("<@80351110224678912>"),
+            Some(80351110224678912.into())
+        );
+        assert_eq!(
+            Mention::User(80351110224678912.into()).to_string(),
+            "<@80351110224678912>"
+        );
+    }
+
+    #[test]
+    fn user_mention_nickname() {
+        assert_eq!(
+            parse_user_mention("<@!80351110224678912>"),
+            Some(80351110224678912.into())
+        );
+    }
+
+    #[test]
+    fn channel_mention() {
+        assert_eq!(
+            parse_channel_mention("<#103735883630395392>"),
+            Some(103735883630395392.into())
+        );
+        assert_eq!(
+            Mention::Channel(103735883630395392.into()).to_string(),
+            "<#103735883630395392>"
+        );
+    }
+
+    #[test]
+    fn role_mention() {
+        assert_eq!(
+            parse_role_mention("<@&165511591545143296>"),
+            Some(165511591545143296.into())
+        );
+        assert_eq!(parse_user_mention("<@&165511591545143296>"), None);
+        assert_eq!(
+            Mention::Role(165511591545143296.into()).to_string(),
+            "<@&165511591545143296>"
+        );
+    }
+
+    #[test]
+    fn custom_emoji() {
+        let emoji = parse_custom_emoji("<:mmLol:216154654256398347>").unwrap();
+
+        assert!(!emoji.animated());
+        assert_eq!(emoji.name(), "mmLol");
+        assert_eq!(emoji.id(), 216154654256398347.into());
+        assert_eq!(emoji.to_string(), "<:mmLol:216154654256398347>");
+    }
+
+    #[test]
+    fn custom_emoji_animated() {
+        let emoji = parse_custom_emoji("<a:b1nzy:392938283556143104>").unwrap();
+
+        assert!(emoji.animated());
+        assert_eq!(emoji.name(), "b1nzy");
+        assert_eq!(emoji.id(), 392938283556143104.into());
+        assert_eq!(emoji.to_string(), "<a:b1nzy:392938283556143104>");
+    }
+
+    #[test]
+    fn parse_any() {
+        assert_eq!(
+            Mention::parse("<@&165511591545143296>"),
+            Some(Mention::Role(165511591545143296.into()))
+        );
+        assert_eq!(
+            Mention::parse("<#103735883630395392>"),
+            Some(Mention::Channel(103735883630395392.into()))
+        );
+    }
+
+    #[test]
+    fn id_mentions() {
+        let user: UserId = 80351110224678912.into();
+        let channel: ChannelId = 103735883630395392.into();
+        let role: RoleId = 165511591545143296.into();
+
+        assert_eq!(user.mention().to_string(), "<@80351110224678912>");
+        assert_eq!(channel.mention().to_string(), "<#103735883630395392>");
+        assert_eq!(
+            format!("hi {}", role.mention()),
+            "hi <@&165511591545143296>"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed() {
+        assert_eq!(parse_user_mention("<@>"), None);
+        assert_eq!(parse_user_mention("<@+123>"), None);
+        assert_eq!(parse_user_mention("@123"), None);
+        assert_eq!(parse_channel_mention("<#123"), None);
+        assert_eq!(parse_custom_emoji("<::123>"), None);
+        assert_eq!(parse_custom_emoji("<:name:>"), None);
+        assert_eq!(Mention::parse("hello"), None);
+    }
+}
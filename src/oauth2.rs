@@ -0,0 +1,172 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::discord::Token;
+use crate::resources::application::ApplicationId;
+use crate::str::{obscure, obscure_opt};
+
+use educe::Educe;
+
+use serde::{Deserialize, Serialize};
+
+/// How an OAuth2 access token is obtained.
+#[derive(Educe, Clone)]
+#[educe(Debug)]
+pub enum Grant {
+    /// Exchange the `code` Discord passed to `redirect_uri` for a token.
+    AuthorizationCode {
+        #[educe(Debug(method = "obscure"))]
+        code: String,
+        redirect_uri: String,
+    },
+
+    /// Obtain a fresh token using a refresh token from an earlier exchange.
+    RefreshToken {
+        #[educe(Debug(method = "obscure"))]
+        refresh_token: String,
+    },
+}
+
+#[derive(Educe, Clone, Serialize)]
+#[educe(Debug)]
+pub(crate) struct NewToken {
+    pub(crate) client_id: ApplicationId,
+
+    #[educe(Debug(method = "obscure"))]
+    pub(crate) client_secret: String,
+
+    pub(crate) grant_type: &'static str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[educe(Debug(method = "obscure_opt"))]
+    pub(crate) code: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) redirect_uri: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[educe(Debug(method = "obscure_opt"))]
+    pub(crate) refresh_token: Option<String>,
+}
+
+impl NewToken {
+    pub(crate) fn new(
+        client_id: ApplicationId,
+        client_secret: String,
+        grant: Grant,
+    ) -> Self {
+        let mut token = Self {
+            client_id,
+            client_secret,
+            grant_type: "",
+            code: None,
+            redirect_uri: None,
+            refresh_token: None,
+        };
+
+        match grant {
+            Grant::AuthorizationCode { code, redirect_uri } => {
+                token.grant_type = "authorization_code";
+                token.code = Some(code);
+                token.redirect_uri = Some(redirect_uri);
+            }
+            Grant::RefreshToken { refresh_token } => {
+                token.grant_type = "refresh_token";
+                token.refresh_token = Some(refresh_token);
+            }
+        }
+
+        token
+    }
+}
+
+#[derive(Educe, Clone, Serialize, Deserialize)]
+#[educe(Debug)]
+pub struct AccessToken {
+    #[educe(Debug(method = "obscure"))]
+    access_token: String,
+    token_type: String,
+    expires_in: u64,
+    #[educe(Debug(method = "obscure_opt"))]
+    refresh_token: Option<String>,
+    scope: String,
+}
+
+impl AccessToken {
+    pub fn access_token(&self) -> &str {
+        &self.access_token
+    }
+
+    pub fn token_type(&self) -> &str {
+        &self.token_type
+    }
+
+    /// Number of seconds until the access token expires.
+    pub fn expires_in(&self) -> u64 {
+        self.expires_in
+    }
+
+    pub fn refresh_token(&self) -> Option<&str> {
+        self.refresh_token.as_deref()
+    }
+
+    /// Space separated list of the scopes granted to the token.
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    pub fn scopes(&self) -> impl Iterator<Item = &str> {
+        self.scope.split_whitespace()
+    }
+
+    /// Create a bearer [`Token`] for use in a [`Config`](crate::Config).
+    pub fn to_token(&self) -> Token {
+        Token::bearer(self.access_token.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_access_token() {
+        let json = json!({
+            "access_token": "6qrZcUqja7812RVdnEKjpzOL4CvHBFG",
+            "token_type": "Bearer",
+            "expires_in": 604800,
+            "refresh_token": "D43f5y0ahjqew82jZ4NViEr2YafMKhue",
+            "scope": "identify guilds"
+        });
+
+        let token: AccessToken = serde_json::from_value(json).unwrap();
+
+        assert_eq!(token.access_token(), "6qrZcUqja7812RVdnEKjpzOL4CvHBFG");
+        assert_eq!(token.token_type(), "Bearer");
+        assert_eq!(token.expires_in(), 604800);
+        assert_eq!(
+            token.refresh_token(),
+            Some("D43f5y0ahjqew82jZ4NViEr2YafMKhue")
+        );
+        assert_eq!(token.scopes().collect::<Vec<_>>(), ["identify", "guilds"]);
+
+        let debug = format!("{:?}", token);
+        assert!(!debug.contains("6qrZcUqja7812RVdnEKjpzOL4CvHBFG"));
+        assert!(!debug.contains("D43f5y0ahjqew82jZ4NViEr2YafMKhue"));
+    }
+
+    #[test]
+    fn grant_debug_hides_code() {
+        let grant = Grant::AuthorizationCode {
+            code: "abc123".into(),
+            redirect_uri: "https://example.com/cb".into(),
+        };
+
+        let debug = format!("{:?}", grant);
+        assert!(!debug.contains("abc123"));
+        assert!(debug.contains("https://example.com/cb"));
+    }
+}
@@ -5,6 +5,7 @@
 use bitflags::bitflags;
 
 use crate::enums::{ParseEnumError, StringEnum};
+use crate::resources::channel::{Channel, Overwrite};
 use crate::resources::guild::{GuildId, IntegrationId};
 use crate::resources::user::BotId;
 use crate::snowflake::Id;
@@ -238,8 +239,98 @@ impl FromStr for Permissions {
     }
 }
 
+/// Compute a member's guild-level permissions, before any channel
+/// overwrites.
+///
+/// `everyone` is the guild's `@everyone` role, and `member_roles` are the
+/// other roles the member has. The guild owner and members with
+/// `ADMINISTRATOR` have every permission.
+pub fn compute_base_permissions(
+    member_roles: &[&Role],
+    everyone: &Role,
+    owner: bool,
+) -> Permissions {
+    if owner {
+        return Permissions::all();
+    }
+
+    let permissions = member_roles
+        .iter()
+        .fold(everyone.permissions(), |acc, role| acc | role.permissions());
+
+    if permissions.contains(Permissions::ADMINISTRATOR) {
+        Permissions::all()
+    } else {
+        permissions
+    }
+}
+
+/// Compute a member's effective permissions in a channel.
+///
+/// `base` is the member's guild-level permissions, as returned by
+/// [`compute_base_permissions`]: the `@everyone` role's permissions ORed
+/// with those of each of the member's roles. `everyone` is
+/// the channel's overwrite for `@everyone`, `roles` pairs each of the member's
+/// roles with that role's overwrite in the channel (roles without one are
+/// left out), and `member` is the overwrite for the member themselves.
+///
+/// Members with `ADMINISTRATOR` have every permission, regardless of
+/// overwrites.
+pub fn compute_permissions(
+    base: Permissions,
+    everyone: &Overwrite,
+    roles: &[(&Role, &Overwrite)],
+    member: Option<&Overwrite>,
+) -> Permissions {
+    if base.contains(Permissions::ADMINISTRATOR) {
+        return Permissions::all();
+    }
+
+    let mut permissions = base;
+
+    permissions &= !everyone.deny();
+    permissions |= everyone.allow();
+
+    let mut allow = Permissions::empty();
+    let mut deny = Permissions::empty();
+
+    for (_, overwrite) in roles {
+        allow |= overwrite.allow();
+        deny |= overwrite.deny();
+    }
+
+    permissions &= !deny;
+    permissions |= allow;
+
+    if let Some(overwrite) = member {
+        permissions &= !overwrite.deny();
+        permissions |= overwrite.allow();
+    }
+
+    permissions
+}
+
+/// The slowmode, in seconds, that applies to a member sending messages in
+/// `channel`, given the member's effective `permissions` there.
+///
+/// Members who can manage messages or the channel are exempt from slowmode,
+/// so this returns `0` for them.
+pub fn effective_slowmode(channel: &Channel, permissions: Permissions) -> u64 {
+    let exempt = Permissions::ADMINISTRATOR
+        | Permissions::MANAGE_MESSAGES
+        | Permissions::MANAGE_CHANNELS;
+
+    if permissions.intersects(exempt) {
+        0
+    } else {
+        channel.rate_limit_per_user().unwrap_or(0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::resources::user::UserId;
+
     use serde_json::json;
 
     use super::*;
@@ -288,6 +379,163 @@ mod tests {
         assert_eq!(role.permissions(), permissions);
     }
 
+    fn role(id: u64, permissions: Permissions) -> Role {
+        let json = json!({
+            "id": id.to_string(),
+            "name": "role",
+            "color": 0,
+            "hoist": false,
+            "position": 1,
+            "permissions": permissions.bits().to_string(),
+            "managed": false,
+            "mentionable": false
+        });
+
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn compute_base_permissions_plain_member() {
+        let everyone = role(41771983423143936, Permissions::VIEW_CHANNEL);
+        let speaker = role(1, Permissions::SEND_MESSAGES);
+        let reactor = role(2, Permissions::ADD_REACTIONS);
+
+        let permissions =
+            compute_base_permissions(&[&speaker, &reactor], &everyone, false);
+
+        assert_eq!(
+            permissions,
+            Permissions::VIEW_CHANNEL
+                | Permissions::SEND_MESSAGES
+                | Permissions::ADD_REACTIONS
+        );
+    }
+
+    #[test]
+    fn compute_base_permissions_administrator() {
+        let everyone = role(41771983423143936, Permissions::VIEW_CHANNEL);
+        let admin = role(1, Permissions::ADMINISTRATOR);
+
+        let permissions = compute_base_permissions(&[&admin], &everyone, false);
+        assert_eq!(permissions, Permissions::all());
+    }
+
+    #[test]
+    fn compute_base_permissions_owner() {
+        let everyone = role(41771983423143936, Permissions::empty());
+
+        let permissions = compute_base_permissions(&[], &everyone, true);
+        assert_eq!(permissions, Permissions::all());
+    }
+
+    #[test]
+    fn compute_permissions_overwrite_order() {
+        let guild_id: GuildId = 41771983423143936.into();
+
+        let base = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES;
+
+        // @everyone can't talk or react here...
+        let everyone = Overwrite::new(
+            RoleId::everyone(guild_id).into(),
+            Permissions::empty(),
+            Permissions::SEND_MESSAGES | Permissions::ADD_REACTIONS,
+        );
+
+        // ...but one role may talk again, and role allows beat role denies.
+        let speaker = role(1, Permissions::empty());
+        let speaker_ow = Overwrite::new(
+            speaker.id().into(),
+            Permissions::SEND_MESSAGES,
+            Permissions::empty(),
+        );
+
+        let muted = role(2, Permissions::empty());
+        let muted_ow = Overwrite::new(
+            muted.id().into(),
+            Permissions::empty(),
+            Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL,
+        );
+
+        let roles = [(&speaker, &speaker_ow), (&muted, &muted_ow)];
+
+        let permissions = compute_permissions(base, &everyone, &roles, None);
+        assert_eq!(
+            permissions,
+            Permissions::SEND_MESSAGES,
+            "VIEW_CHANNEL denied by role, SEND_MESSAGES re-allowed by role"
+        );
+
+        // The member overwrite is applied last.
+        let member = Overwrite::new(
+            UserId::from(3).into(),
+            Permissions::VIEW_CHANNEL | Permissions::ADD_REACTIONS,
+            Permissions::SEND_MESSAGES,
+        );
+
+        let permissions =
+            compute_permissions(base, &everyone, &roles, Some(&member));
+        assert_eq!(
+            permissions,
+            Permissions::VIEW_CHANNEL | Permissions::ADD_REACTIONS
+        );
+    }
+
+    #[test]
+    fn compute_permissions_administrator() {
+        let guild_id: GuildId = 41771983423143936.into();
+
+        let everyone = Overwrite::new(
+            RoleId::everyone(guild_id).into(),
+            Permissions::empty(),
+            Permissions::all(),
+        );
+
+        let member = Overwrite::new(
+            UserId::from(3).into(),
+            Permissions::empty(),
+            Permissions::all(),
+        );
+
+        let permissions = compute_permissions(
+            Permissions::ADMINISTRATOR,
+            &everyone,
+            &[],
+            Some(&member),
+        );
+
+        assert_eq!(permissions, Permissions::all());
+    }
+
+    fn slow_channel() -> Channel {
+        serde_json::from_value(json!({
+            "id": "41771983423143937",
+            "type": 0,
+            "rate_limit_per_user": 30
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn effective_slowmode_applies() {
+        let permissions =
+            Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES;
+        assert_eq!(effective_slowmode(&slow_channel(), permissions), 30);
+    }
+
+    #[test]
+    fn effective_slowmode_exempt() {
+        let channel = slow_channel();
+
+        assert_eq!(
+            effective_slowmode(&channel, Permissions::MANAGE_MESSAGES),
+            0
+        );
+        assert_eq!(
+            effective_slowmode(&channel, Permissions::MANAGE_CHANNELS),
+            0
+        );
+    }
+
     #[test]
     fn deserialize_role_tag_normal() {
         let json = json!({});
@@ -4,9 +4,11 @@
 
 pub mod application;
 pub mod audit_log;
+pub mod auto_moderation;
 pub mod channel;
 pub mod emoji;
 pub mod guild;
+pub mod guild_scheduled_event;
 pub mod guild_template;
 pub mod invite;
 pub mod stage_instance;
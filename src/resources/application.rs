@@ -3,6 +3,7 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 mod commands;
+mod role_connection;
 
 use bitflags::bitflags;
 
@@ -15,6 +16,7 @@ use crate::snowflake::Id;
 use crate::teams::Team;
 
 pub use self::commands::*;
+pub use self::role_connection::*;
 
 use serde::{Deserialize, Serialize};
 
@@ -25,6 +27,7 @@ bitflags! {
     pub struct ApplicationFlags: u64 {
         const MANAGED_EMOJI = 1<<2;
         const GROUP_DM_CREATE = 1<<4;
+        const APPLICATION_AUTO_MODERATION_RULE_CREATE_BADGE = 1<<6;
         const RPC_HAS_CONNECTED = 1<<11;
         const GATEWAY_PRESENCE = 1<<12;
         const GATEWAY_PRESENCE_LIMITED = 1<<13;
@@ -32,6 +35,9 @@ bitflags! {
         const GATEWAY_GUILD_MEMBERS_LIMITED = 1<<15;
         const VERIFICATION_PENDING_GUILD_LIMIT = 1<<16;
         const EMBEDDED = 1<<17;
+        const GATEWAY_MESSAGE_CONTENT = 1<<18;
+        const GATEWAY_MESSAGE_CONTENT_LIMITED = 1<<19;
+        const APPLICATION_COMMAND_BADGE = 1<<23;
     }
 }
 
@@ -186,14 +192,17 @@ impl Application {
 #[cfg(test)]
 mod tests {
     use crate::image::Image;
+    use crate::mock::{MockResponse, MockServer};
+    use crate::requests::GetCurrentApplication;
+    use crate::round_trip::assert_round_trip;
+    use crate::teams::MembershipState;
 
     use serde_json::json;
 
     use super::*;
 
-    #[test]
-    fn deserialize_application() {
-        let json = json!({
+    fn application_json() -> serde_json::Value {
+        json!({
             "bot_public": true,
             "bot_require_code_grant": false,
             "cover_image": "31deabb7e45b6c8ecfef77d2f99c81a5",
@@ -230,7 +239,17 @@ mod tests {
                 ]
             },
             "verify_key": "1e0a356058d627ca38a5c8c9648818061d49e49bd9da9e3ab17d98ad4d6bg2u8"
-        });
+        })
+    }
+
+    #[test]
+    fn application_round_trip() {
+        assert_round_trip::<Application>(application_json());
+    }
+
+    #[test]
+    fn deserialize_application() {
+        let json = application_json();
 
         let app: Application = serde_json::from_value(json).unwrap();
 
@@ -254,7 +273,18 @@ mod tests {
             "1e0a356058d627ca38a5c8c9648818061d49e49bd9da9e3ab17d98ad4d6bg2u8"
         );
 
-        // TODO: Team
+        let team = app.team().unwrap();
+        assert_eq!(team.id(), 531992624043786253.into());
+        assert_eq!(
+            team.icon().unwrap().bare_path(),
+            "team-icons/531992624043786253/dd9b7dcfdf5351b9c3de0fe167bacbe1"
+        );
+
+        let member = &team.members()[0];
+        assert_eq!(member.membership_state(), MembershipState::Accepted);
+        assert_eq!(member.permissions(), ["*"]);
+        assert_eq!(member.team_id(), team.id());
+        assert_eq!(member.user().username(), "Mr Owner");
 
         let owner = app.owner();
         assert_eq!(owner.username(), "i own a bot");
@@ -262,4 +292,38 @@ mod tests {
         assert_eq!(owner.id(), 172150183260323840.into());
         assert_eq!(owner.avatar_or_default().bare_path(), "embed/avatars/3");
     }
+
+    #[test]
+    fn deserialize_application_command_badge() {
+        let mut json = application_json();
+        json["flags"] = json!((1 << 23) | (1 << 18) | (1 << 6));
+
+        let app: Application = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            app.try_flags(),
+            Some(Ok(ApplicationFlags::APPLICATION_COMMAND_BADGE
+                | ApplicationFlags::GATEWAY_MESSAGE_CONTENT
+                | ApplicationFlags::APPLICATION_AUTO_MODERATION_RULE_CREATE_BADGE))
+        );
+    }
+
+    #[tokio::test]
+    async fn get_current_application() {
+        let server =
+            MockServer::always(MockResponse::json(200, application_json()));
+
+        let app = GetCurrentApplication::builder()
+            .build()
+            .send(&server.discord())
+            .await
+            .unwrap();
+
+        assert_eq!(app.id(), 172150183260323840.into());
+        assert_eq!(app.name(), "Baba O-Riley");
+
+        let request = server.only_request();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/oauth2/applications/@me");
+    }
 }
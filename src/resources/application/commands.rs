@@ -6,13 +6,19 @@ use bitflags::bitflags;
 
 use crate::enums::{EnumFromIntegerError, IntegerEnum};
 use crate::permissions::RoleId;
-use crate::resources::channel::{AllowedMentions, Embed};
+use crate::resources::channel::{
+    AllowedMentions, ChannelId, ChannelKind, Component, Embed,
+};
 use crate::resources::guild::GuildId;
 use crate::resources::user::UserId;
 use crate::snowflake::Id;
+use crate::str::obscure;
+
+use educe::Educe;
 
 use serde::{Deserialize, Serialize};
 
+use std::collections::HashMap;
 use std::convert::TryFrom;
 
 use super::ApplicationId;
@@ -74,7 +80,9 @@ impl From<u64> for ChoiceValue {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
+#[derive(
+    Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, TypedBuilder,
+)]
 pub struct ApplicationCommandOptionChoice {
     #[builder(setter(into))]
     name: String,
@@ -83,7 +91,42 @@ pub struct ApplicationCommandOptionChoice {
     value: ChoiceValue,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ApplicationCommandKind {
+    /// A slash command.
+    ChatInput,
+    /// Shown when right clicking on a user.
+    User,
+    /// Shown when right clicking on a message.
+    Message,
+}
+
+impl From<ApplicationCommandKind> for u64 {
+    fn from(u: ApplicationCommandKind) -> Self {
+        match u {
+            ApplicationCommandKind::ChatInput => 1,
+            ApplicationCommandKind::User => 2,
+            ApplicationCommandKind::Message => 3,
+        }
+    }
+}
+
+impl TryFrom<u64> for ApplicationCommandKind {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            1 => Self::ChatInput,
+            2 => Self::User,
+            3 => Self::Message,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum ApplicationCommandOptionKind {
     SubCommand,
     SubCommandGroup,
@@ -94,6 +137,8 @@ pub enum ApplicationCommandOptionKind {
     Channel,
     Role,
     Mentionable,
+    /// A double-precision floating point number.
+    Number,
 }
 
 impl From<ApplicationCommandOptionKind> for u64 {
@@ -108,6 +153,7 @@ impl From<ApplicationCommandOptionKind> for u64 {
             ApplicationCommandOptionKind::Channel => 7,
             ApplicationCommandOptionKind::Role => 8,
             ApplicationCommandOptionKind::Mentionable => 9,
+            ApplicationCommandOptionKind::Number => 10,
         }
     }
 }
@@ -126,6 +172,7 @@ impl TryFrom<u64> for ApplicationCommandOptionKind {
             7 => Self::Channel,
             8 => Self::Role,
             9 => Self::Mentionable,
+            10 => Self::Number,
 
             other => return Err(EnumFromIntegerError::new(other)),
         };
@@ -134,7 +181,9 @@ impl TryFrom<u64> for ApplicationCommandOptionKind {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
+/// Options compare structurally, but aren't `Eq`, since `min_value` and
+/// `max_value` may be floats.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TypedBuilder)]
 pub struct ApplicationCommandOption {
     #[builder(setter(into))]
     #[serde(rename = "type")]
@@ -143,9 +192,17 @@ pub struct ApplicationCommandOption {
     #[builder(setter(into))]
     name: String,
 
+    #[builder(default, setter(into, strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name_localizations: Option<HashMap<String, String>>,
+
     #[builder(setter(into))]
     description: String,
 
+    #[builder(default, setter(into, strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description_localizations: Option<HashMap<String, String>>,
+
     #[builder(default, setter(strip_option))]
     required: Option<bool>,
 
@@ -154,6 +211,145 @@ pub struct ApplicationCommandOption {
 
     #[builder(default, setter(into, strip_option))]
     options: Option<Vec<ApplicationCommandOption>>,
+
+    /// Restricts `Channel` options to these kinds of channel.
+    #[builder(default, setter(into, strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_types: Option<Vec<IntegerEnum<ChannelKind>>>,
+
+    #[builder(default, setter(into, strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_value: Option<OptionValue>,
+
+    #[builder(default, setter(into, strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_value: Option<OptionValue>,
+
+    #[builder(default, setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    autocomplete: Option<bool>,
+}
+
+impl ApplicationCommandOption {
+    pub fn try_kind(
+        &self,
+    ) -> Result<ApplicationCommandOptionKind, EnumFromIntegerError> {
+        self.kind.try_unwrap()
+    }
+
+    pub fn kind(&self) -> ApplicationCommandOptionKind {
+        self.kind.unwrap()
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn name_localizations(&self) -> Option<&HashMap<String, String>> {
+        self.name_localizations.as_ref()
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn description_localizations(
+        &self,
+    ) -> Option<&HashMap<String, String>> {
+        self.description_localizations.as_ref()
+    }
+
+    pub fn required(&self) -> Option<bool> {
+        self.required
+    }
+
+    pub fn choices(&self) -> Option<&[ApplicationCommandOptionChoice]> {
+        self.choices.as_deref()
+    }
+
+    pub fn options(&self) -> Option<&[ApplicationCommandOption]> {
+        self.options.as_deref()
+    }
+
+    pub fn channel_types(&self) -> Option<&[IntegerEnum<ChannelKind>]> {
+        self.channel_types.as_deref()
+    }
+
+    pub fn min_value(&self) -> Option<OptionValue> {
+        self.min_value
+    }
+
+    pub fn max_value(&self) -> Option<OptionValue> {
+        self.max_value
+    }
+
+    pub fn autocomplete(&self) -> Option<bool> {
+        self.autocomplete
+    }
+
+    /// Whether this option is the same as `other`, once Discord's defaults
+    /// are filled in.
+    ///
+    /// Discord leaves out `required` and `autocomplete` when they're false,
+    /// and leaves out empty lists, so those compare equal to unset fields.
+    fn matches(&self, other: &Self) -> bool {
+        self.kind == other.kind
+            && self.name == other.name
+            && self.description == other.description
+            && localizations(&self.name_localizations)
+                == localizations(&other.name_localizations)
+            && localizations(&self.description_localizations)
+                == localizations(&other.description_localizations)
+            && self.required.unwrap_or(false) == other.required.unwrap_or(false)
+            && self.choices.as_deref().unwrap_or_default()
+                == other.choices.as_deref().unwrap_or_default()
+            && options_match(&self.options, &other.options)
+            && self.channel_types.as_deref().unwrap_or_default()
+                == other.channel_types.as_deref().unwrap_or_default()
+            && self.min_value == other.min_value
+            && self.max_value == other.max_value
+            && self.autocomplete.unwrap_or(false)
+                == other.autocomplete.unwrap_or(false)
+    }
+}
+
+/// Treat an empty map of localizations the same as a missing one.
+fn localizations(
+    map: &Option<HashMap<String, String>>,
+) -> Option<&HashMap<String, String>> {
+    map.as_ref().filter(|m| !m.is_empty())
+}
+
+/// Compare lists of options with [`ApplicationCommandOption::matches`],
+/// treating a missing list the same as an empty one.
+fn options_match(
+    a: &Option<Vec<ApplicationCommandOption>>,
+    b: &Option<Vec<ApplicationCommandOption>>,
+) -> bool {
+    let a = a.as_deref().unwrap_or_default();
+    let b = b.as_deref().unwrap_or_default();
+
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.matches(b))
+}
+
+/// A bound on an `Integer` or `Number` option.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OptionValue {
+    Integer(i64),
+    Number(f64),
+}
+
+impl From<i64> for OptionValue {
+    fn from(i: i64) -> Self {
+        Self::Integer(i)
+    }
+}
+
+impl From<f64> for OptionValue {
+    fn from(f: f64) -> Self {
+        Self::Number(f)
+    }
 }
 
 pub type ApplicationCommandId = Id<ApplicationCommand>;
@@ -161,9 +357,13 @@ pub type ApplicationCommandId = Id<ApplicationCommand>;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApplicationCommand {
     id: ApplicationCommandId,
+    #[serde(rename = "type")]
+    kind: Option<IntegerEnum<ApplicationCommandKind>>,
     application_id: ApplicationId,
     name: String,
+    name_localizations: Option<HashMap<String, String>>,
     description: String,
+    description_localizations: Option<HashMap<String, String>>,
     options: Option<Vec<ApplicationCommandOption>>,
     default_permission: Option<bool>,
 }
@@ -173,6 +373,19 @@ impl ApplicationCommand {
         self.id
     }
 
+    /// The kind of command. A missing `type` means a chat input command.
+    pub fn try_kind(
+        &self,
+    ) -> Result<ApplicationCommandKind, EnumFromIntegerError> {
+        self.kind
+            .map(IntegerEnum::try_unwrap)
+            .unwrap_or(Ok(ApplicationCommandKind::ChatInput))
+    }
+
+    pub fn kind(&self) -> ApplicationCommandKind {
+        self.try_kind().unwrap()
+    }
+
     pub fn application_id(&self) -> ApplicationId {
         self.application_id
     }
@@ -181,10 +394,22 @@ impl ApplicationCommand {
         &self.name
     }
 
+    /// Localized names, keyed by locale (like `de` or `en-US`).
+    pub fn name_localizations(&self) -> Option<&HashMap<String, String>> {
+        self.name_localizations.as_ref()
+    }
+
     pub fn description(&self) -> &str {
         &self.description
     }
 
+    /// Localized descriptions, keyed by locale (like `de` or `en-US`).
+    pub fn description_localizations(
+        &self,
+    ) -> Option<&HashMap<String, String>> {
+        self.description_localizations.as_ref()
+    }
+
     pub fn options(&self) -> Option<&[ApplicationCommandOption]> {
         self.options.as_deref()
     }
@@ -194,14 +419,30 @@ impl ApplicationCommand {
     }
 }
 
-#[derive(Debug, Clone, Serialize, TypedBuilder)]
+/// A command to create.
+///
+/// `description` is required for chat input commands, and must be left empty
+/// for user and message commands.
+#[derive(Debug, Clone, PartialEq, Serialize, TypedBuilder)]
 pub struct NewApplicationCommand {
+    #[builder(default, setter(strip_option, into))]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub(crate) kind: Option<IntegerEnum<ApplicationCommandKind>>,
+
     #[builder(setter(into))]
     pub(crate) name: String,
 
-    #[builder(setter(into))]
+    #[builder(default, setter(strip_option, into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name_localizations: Option<HashMap<String, String>>,
+
+    #[builder(default, setter(into))]
     pub(crate) description: String,
 
+    #[builder(default, setter(strip_option, into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description_localizations: Option<HashMap<String, String>>,
+
     #[builder(default, setter(strip_option, into))]
     pub(crate) options: Option<Vec<ApplicationCommandOption>>,
 
@@ -210,14 +451,41 @@ pub struct NewApplicationCommand {
     pub(crate) default_permission: Option<bool>,
 }
 
+impl NewApplicationCommand {
+    /// Whether registering this command would leave `existing` unchanged.
+    ///
+    /// Unset fields are compared as Discord's defaults, so a missing list of
+    /// options matches an empty one.
+    pub(crate) fn matches(&self, existing: &ApplicationCommand) -> bool {
+        let chat_input = ApplicationCommandKind::ChatInput.into();
+
+        self.kind.unwrap_or(chat_input) == existing.kind.unwrap_or(chat_input)
+            && self.name == existing.name
+            && self.description == existing.description
+            && localizations(&self.name_localizations)
+                == localizations(&existing.name_localizations)
+            && localizations(&self.description_localizations)
+                == localizations(&existing.description_localizations)
+            && options_match(&self.options, &existing.options)
+            && self.default_permission.unwrap_or(true)
+                == existing.default_permission.unwrap_or(true)
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub(crate) struct EditApplicationCommand {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<HashMap<String, String>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<HashMap<String, String>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub options: Option<Vec<ApplicationCommandOption>>,
 
@@ -225,6 +493,59 @@ pub(crate) struct EditApplicationCommand {
     pub default_permission: Option<bool>,
 }
 
+/// Identifies an interaction for its response and followup endpoints.
+///
+/// The token is valid for 15 minutes after the interaction is received, and
+/// authenticates those endpoints on its own.
+#[derive(Educe, Clone)]
+#[educe(Debug)]
+pub struct InteractionToken {
+    application_id: ApplicationId,
+    #[educe(Debug(method = "obscure"))]
+    token: String,
+}
+
+impl InteractionToken {
+    pub fn new<S>(application_id: ApplicationId, token: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            application_id,
+            token: token.into(),
+        }
+    }
+
+    pub fn application_id(&self) -> ApplicationId {
+        self.application_id
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// The path of a message sent in response to the interaction, or of the
+    /// original response when `message` is `@original`.
+    pub(crate) fn message_path(
+        &self,
+        message: impl std::fmt::Display,
+    ) -> String {
+        format!(
+            "webhooks/{}/{}/messages/{}",
+            self.application_id, self.token, message
+        )
+    }
+}
+
+impl<S> From<(ApplicationId, S)> for InteractionToken
+where
+    S: Into<String>,
+{
+    fn from((application_id, token): (ApplicationId, S)) -> Self {
+        Self::new(application_id, token)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 pub struct InteractionResponse {
     #[serde(rename = "type")]
@@ -233,7 +554,129 @@ pub struct InteractionResponse {
 
     #[builder(default, setter(strip_option, into))]
     #[serde(skip_serializing_if = "Option::is_none")]
-    data: Option<InteractionApplicationCommandCallbackData>,
+    data: Option<InteractionCallbackData>,
+}
+
+impl InteractionResponse {
+    /// Respond by showing a popup form to the user.
+    pub fn modal<I, T, C>(custom_id: I, title: T, components: C) -> Self
+    where
+        I: Into<String>,
+        T: Into<String>,
+        C: Into<Vec<Component>>,
+    {
+        let data = InteractionModalCallbackData::builder()
+            .custom_id(custom_id)
+            .title(title)
+            .components(components)
+            .build();
+
+        Self::builder()
+            .kind(InteractionCallbackKind::Modal)
+            .data(data)
+            .build()
+    }
+
+    /// Respond to an autocomplete interaction with suggested values.
+    ///
+    /// Discord accepts at most 25 choices, and rejects the response if there
+    /// are more.
+    pub fn autocomplete<C>(choices: C) -> Self
+    where
+        C: Into<Vec<ApplicationCommandOptionChoice>>,
+    {
+        let data = InteractionAutocompleteCallbackData::builder()
+            .choices(choices)
+            .build();
+
+        Self::builder()
+            .kind(InteractionCallbackKind::ApplicationCommandAutocompleteResult)
+            .data(data)
+            .build()
+    }
+
+    pub fn try_kind(
+        &self,
+    ) -> Result<InteractionCallbackKind, EnumFromIntegerError> {
+        self.kind.try_unwrap()
+    }
+
+    pub fn kind(&self) -> InteractionCallbackKind {
+        self.kind.unwrap()
+    }
+
+    pub fn data(&self) -> Option<&InteractionCallbackData> {
+        self.data.as_ref()
+    }
+}
+
+/// The `data` of an [`InteractionResponse`], which depends on its kind.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum InteractionCallbackData {
+    Modal(InteractionModalCallbackData),
+    Autocomplete(InteractionAutocompleteCallbackData),
+    Message(InteractionApplicationCommandCallbackData),
+}
+
+impl From<InteractionModalCallbackData> for InteractionCallbackData {
+    fn from(data: InteractionModalCallbackData) -> Self {
+        Self::Modal(data)
+    }
+}
+
+impl From<InteractionAutocompleteCallbackData> for InteractionCallbackData {
+    fn from(data: InteractionAutocompleteCallbackData) -> Self {
+        Self::Autocomplete(data)
+    }
+}
+
+impl From<InteractionApplicationCommandCallbackData>
+    for InteractionCallbackData
+{
+    fn from(data: InteractionApplicationCommandCallbackData) -> Self {
+        Self::Message(data)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
+pub struct InteractionModalCallbackData {
+    #[builder(setter(into))]
+    custom_id: String,
+
+    #[builder(setter(into))]
+    title: String,
+
+    /// Between 1 and 5 action rows.
+    #[builder(setter(into))]
+    components: Vec<Component>,
+}
+
+impl InteractionModalCallbackData {
+    pub fn custom_id(&self) -> &str {
+        &self.custom_id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn components(&self) -> &[Component] {
+        &self.components
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
+pub struct InteractionAutocompleteCallbackData {
+    /// At most 25 choices.
+    #[builder(setter(into))]
+    choices: Vec<ApplicationCommandOptionChoice>,
+}
+
+impl InteractionAutocompleteCallbackData {
+    pub fn choices(&self) -> &[ApplicationCommandOptionChoice] {
+        &self.choices
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
@@ -241,6 +684,12 @@ pub enum InteractionCallbackKind {
     Pong,
     ChannelMessageWithSource,
     DeferredChannelMessageWithSource,
+    /// For components, acknowledge now and edit the original message later.
+    DeferredUpdateMessage,
+    /// For components, edit the message the component was attached to.
+    UpdateMessage,
+    ApplicationCommandAutocompleteResult,
+    Modal,
 }
 
 impl From<InteractionCallbackKind> for u64 {
@@ -249,6 +698,10 @@ impl From<InteractionCallbackKind> for u64 {
             InteractionCallbackKind::Pong => 1,
             InteractionCallbackKind::ChannelMessageWithSource => 4,
             InteractionCallbackKind::DeferredChannelMessageWithSource => 5,
+            InteractionCallbackKind::DeferredUpdateMessage => 6,
+            InteractionCallbackKind::UpdateMessage => 7,
+            InteractionCallbackKind::ApplicationCommandAutocompleteResult => 8,
+            InteractionCallbackKind::Modal => 9,
         }
     }
 }
@@ -261,6 +714,10 @@ impl TryFrom<u64> for InteractionCallbackKind {
             1 => InteractionCallbackKind::Pong,
             4 => InteractionCallbackKind::ChannelMessageWithSource,
             5 => InteractionCallbackKind::DeferredChannelMessageWithSource,
+            6 => InteractionCallbackKind::DeferredUpdateMessage,
+            7 => InteractionCallbackKind::UpdateMessage,
+            8 => InteractionCallbackKind::ApplicationCommandAutocompleteResult,
+            9 => InteractionCallbackKind::Modal,
 
             other => return Err(EnumFromIntegerError::new(other)),
         };
@@ -290,6 +747,23 @@ pub struct InteractionApplicationCommandCallbackData {
     #[builder(default, setter(into, strip_option))]
     #[serde(skip_serializing_if = "Option::is_none")]
     flags: Option<IntegerEnum<InteractionCallbackFlags>>,
+
+    #[builder(default, setter(into, strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<Component>>,
+}
+
+impl InteractionApplicationCommandCallbackData {
+    /// A message with `content` that only the invoking user can see.
+    pub fn ephemeral<S>(content: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::builder()
+            .content(content)
+            .flags(InteractionCallbackFlags::EPHEMERAL)
+            .build()
+    }
 }
 
 bitflags! {
@@ -365,28 +839,61 @@ impl From<CommandPermissionId> for CmdPermIdHelper {
                 id: u64::from(uid).into(),
                 kind: 2,
             },
+            CommandPermissionId::Channel(cid) => Self {
+                id: u64::from(cid).into(),
+                kind: 3,
+            },
         }
     }
 }
 
-impl From<CmdPermIdHelper> for CommandPermissionId {
-    fn from(cpi: CmdPermIdHelper) -> Self {
-        match cpi {
+impl TryFrom<CmdPermIdHelper> for CommandPermissionId {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(cpi: CmdPermIdHelper) -> Result<Self, Self::Error> {
+        let r = match cpi {
             CmdPermIdHelper { id, kind: 1 } => Self::Role(u64::from(id).into()),
             CmdPermIdHelper { id, kind: 2 } => Self::User(u64::from(id).into()),
-            _ => panic!("unsupported command permission id"),
-        }
+            CmdPermIdHelper { id, kind: 3 } => {
+                Self::Channel(u64::from(id).into())
+            }
+            CmdPermIdHelper { kind, .. } => {
+                return Err(EnumFromIntegerError::new(kind))
+            }
+        };
+
+        Ok(r)
     }
 }
 
+/// The target of an [`ApplicationCommandPermission`].
+///
+/// Discord uses two sentinel ids: the guild's own id as a role means
+/// `@everyone`, and the guild's id minus one as a channel means every channel
+/// in the guild. See [`CommandPermissionId::everyone`] and
+/// [`CommandPermissionId::all_channels`].
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
-#[serde(from = "CmdPermIdHelper", into = "CmdPermIdHelper")]
+#[serde(try_from = "CmdPermIdHelper", into = "CmdPermIdHelper")]
 pub enum CommandPermissionId {
     Role(RoleId),
     User(UserId),
+    Channel(ChannelId),
 }
 
 impl CommandPermissionId {
+    /// Targets every member of the guild.
+    pub fn everyone(guild_id: GuildId) -> Self {
+        Self::Role(u64::from(guild_id).into())
+    }
+
+    /// Targets every channel in the guild.
+    ///
+    /// Returns `None` for a guild id of zero, which no real guild has.
+    pub fn all_channels(guild_id: GuildId) -> Option<Self> {
+        let id = u64::from(guild_id).checked_sub(1)?;
+        Some(Self::Channel(id.into()))
+    }
+
     pub fn is_user(self) -> bool {
         matches!(self, Self::User(_))
     }
@@ -394,6 +901,18 @@ impl CommandPermissionId {
     pub fn is_role(self) -> bool {
         matches!(self, Self::Role(_))
     }
+
+    pub fn is_channel(self) -> bool {
+        matches!(self, Self::Channel(_))
+    }
+
+    pub fn is_everyone(self, guild_id: GuildId) -> bool {
+        self == Self::everyone(guild_id)
+    }
+
+    pub fn is_all_channels(self, guild_id: GuildId) -> bool {
+        Self::all_channels(guild_id) == Some(self)
+    }
 }
 
 impl From<UserId> for CommandPermissionId {
@@ -408,6 +927,12 @@ impl From<RoleId> for CommandPermissionId {
     }
 }
 
+impl From<ChannelId> for CommandPermissionId {
+    fn from(cid: ChannelId) -> Self {
+        CommandPermissionId::Channel(cid)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 pub struct ApplicationCommandPermission {
     #[builder(setter(into))]
@@ -428,10 +953,408 @@ impl ApplicationCommandPermission {
 
 #[cfg(test)]
 mod tests {
+    use crate::resources::channel::{ButtonStyle, ComponentKind};
+
     use serde_json::json;
 
     use super::*;
 
+    use std::collections::HashSet;
+
+    fn color_option() -> ApplicationCommandOption {
+        let choices = vec![
+            ApplicationCommandOptionChoice::builder()
+                .name("Red")
+                .value("red")
+                .build(),
+            ApplicationCommandOptionChoice::builder()
+                .name("Blue")
+                .value("blue")
+                .build(),
+        ];
+
+        ApplicationCommandOption::builder()
+            .kind(ApplicationCommandOptionKind::String)
+            .name("color")
+            .description("Pick a color")
+            .choices(choices)
+            .build()
+    }
+
+    #[test]
+    fn options_compare_structurally() {
+        assert_eq!(color_option(), color_option());
+
+        let mut other = color_option();
+        other.required = Some(true);
+        assert_ne!(color_option(), other);
+
+        let choices: HashSet<_> =
+            color_option().choices().unwrap().iter().cloned().collect();
+        assert_eq!(choices.len(), 2);
+    }
+
+    #[test]
+    fn deserialize_application_command_kinds() {
+        let mut json = json!({
+            "id": "150",
+            "application_id": "775799577604522054",
+            "name": "permissions",
+            "description": "Get or edit permissions for a user or a role",
+        });
+
+        let command: ApplicationCommand =
+            serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(command.kind(), ApplicationCommandKind::ChatInput);
+
+        json["type"] = json!(2);
+        json["description"] = json!("");
+
+        let command: ApplicationCommand =
+            serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(command.kind(), ApplicationCommandKind::User);
+        assert_eq!(command.description(), "");
+
+        json["type"] = json!(42);
+
+        let command: ApplicationCommand = serde_json::from_value(json).unwrap();
+        assert_eq!(command.try_kind().unwrap_err().inner(), 42);
+    }
+
+    #[test]
+    fn serialize_new_application_command_user() {
+        let command = NewApplicationCommand::builder()
+            .kind(ApplicationCommandKind::User)
+            .name("High Five")
+            .build();
+
+        let json = serde_json::to_value(&command).unwrap();
+
+        assert_eq!(
+            json,
+            json!({
+                "type": 2,
+                "name": "High Five",
+                "description": "",
+                "options": null
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_new_application_command_localized() {
+        let mut names = HashMap::new();
+        names.insert("de".to_owned(), "hallo".to_owned());
+
+        let mut descriptions = HashMap::new();
+        descriptions.insert("de".to_owned(), "Sag hallo".to_owned());
+
+        let command = NewApplicationCommand::builder()
+            .name("hello")
+            .name_localizations(names)
+            .description("Say hello")
+            .description_localizations(descriptions)
+            .build();
+
+        let json = serde_json::to_value(&command).unwrap();
+
+        assert_eq!(json["name_localizations"], json!({ "de": "hallo" }));
+        assert_eq!(
+            json["description_localizations"],
+            json!({ "de": "Sag hallo" })
+        );
+
+        let command = NewApplicationCommand::builder()
+            .name("hello")
+            .description("Say hello")
+            .build();
+
+        let json = serde_json::to_value(&command).unwrap();
+
+        assert!(json.get("name_localizations").is_none());
+        assert!(json.get("description_localizations").is_none());
+    }
+
+    #[test]
+    fn new_application_command_matches_discord_defaults() {
+        let subcommand = ApplicationCommandOption::builder()
+            .kind(ApplicationCommandOptionKind::SubCommand)
+            .name("say")
+            .description("Say something")
+            .options(vec![ApplicationCommandOption::builder()
+                .kind(ApplicationCommandOptionKind::String)
+                .name("text")
+                .description("What to say")
+                .required(false)
+                .autocomplete(false)
+                .choices(vec![])
+                .build()])
+            .build();
+
+        let command = NewApplicationCommand::builder()
+            .name("echo")
+            .description("Repeat something")
+            .options(vec![subcommand])
+            .build();
+
+        let json = json!({
+            "id": "775799577604522058",
+            "application_id": "775799577604522054",
+            "name": "echo",
+            "description": "Repeat something",
+            "options": [{
+                "type": 1,
+                "name": "say",
+                "description": "Say something",
+                "options": [{
+                    "type": 3,
+                    "name": "text",
+                    "description": "What to say"
+                }]
+            }]
+        });
+
+        let existing: ApplicationCommand =
+            serde_json::from_value(json.clone()).unwrap();
+        assert!(command.matches(&existing));
+
+        let mut json = json;
+        json["options"][0]["options"][0]["required"] = json!(true);
+
+        let existing: ApplicationCommand =
+            serde_json::from_value(json).unwrap();
+        assert!(!command.matches(&existing));
+    }
+
+    #[test]
+    fn deserialize_application_command_localized() {
+        let json = json!({
+            "id": "150",
+            "application_id": "775799577604522054",
+            "name": "hello",
+            "name_localizations": { "de": "hallo" },
+            "description": "Say hello",
+            "description_localizations": null
+        });
+
+        let command: ApplicationCommand = serde_json::from_value(json).unwrap();
+
+        let names = command.name_localizations().unwrap();
+        assert_eq!(names.get("de").map(String::as_str), Some("hallo"));
+        assert!(command.description_localizations().is_none());
+    }
+
+    #[test]
+    fn serialize_ephemeral_callback_with_components() {
+        let mut data = InteractionApplicationCommandCallbackData::ephemeral(
+            "Only you can see this",
+        );
+
+        data.components =
+            Some(vec![Component::action_row(vec![Component::builder()
+                .kind(ComponentKind::Button)
+                .style(ButtonStyle::Primary)
+                .label("Click me")
+                .custom_id("click_one")
+                .build()])]);
+
+        let json = serde_json::to_value(&data).unwrap();
+
+        assert_eq!(
+            json,
+            json!({
+                "content": "Only you can see this",
+                "flags": 64,
+                "components": [{
+                    "type": 1,
+                    "components": [{
+                        "type": 2,
+                        "style": 1,
+                        "label": "Click me",
+                        "custom_id": "click_one"
+                    }]
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn interaction_callback_kind_integers() {
+        let kinds = [
+            (InteractionCallbackKind::DeferredUpdateMessage, 6),
+            (InteractionCallbackKind::UpdateMessage, 7),
+            (
+                InteractionCallbackKind::ApplicationCommandAutocompleteResult,
+                8,
+            ),
+            (InteractionCallbackKind::Modal, 9),
+        ];
+
+        for (kind, raw) in kinds.iter() {
+            assert_eq!(u64::from(*kind), *raw);
+            assert_eq!(InteractionCallbackKind::try_from(*raw), Ok(*kind));
+        }
+    }
+
+    #[test]
+    fn serialize_modal_response() {
+        let response = InteractionResponse::modal(
+            "feedback",
+            "Tell us what you think",
+            vec![Component::action_row(vec![])],
+        );
+
+        let json = serde_json::to_value(&response).unwrap();
+
+        assert_eq!(
+            json,
+            json!({
+                "type": 9,
+                "data": {
+                    "custom_id": "feedback",
+                    "title": "Tell us what you think",
+                    "components": [{ "type": 1, "components": [] }]
+                }
+            })
+        );
+
+        let response: InteractionResponse =
+            serde_json::from_value(json).unwrap();
+
+        assert_eq!(response.kind(), InteractionCallbackKind::Modal);
+        let data = match response.data() {
+            Some(InteractionCallbackData::Modal(data)) => data,
+            other => panic!("expected modal data, got {:?}", other),
+        };
+        assert_eq!(data.title(), "Tell us what you think");
+    }
+
+    #[test]
+    fn serialize_autocomplete_response() {
+        let choices = vec![
+            ApplicationCommandOptionChoice::builder()
+                .name("Red")
+                .value("red")
+                .build(),
+            ApplicationCommandOptionChoice::builder()
+                .name("Green")
+                .value("green")
+                .build(),
+            ApplicationCommandOptionChoice::builder()
+                .name("Blue")
+                .value("blue")
+                .build(),
+        ];
+
+        let response = InteractionResponse::autocomplete(choices);
+
+        let json = serde_json::to_value(&response).unwrap();
+
+        assert_eq!(
+            json,
+            json!({
+                "type": 8,
+                "data": {
+                    "choices": [
+                        { "name": "Red", "value": "red" },
+                        { "name": "Green", "value": "green" },
+                        { "name": "Blue", "value": "blue" }
+                    ]
+                }
+            })
+        );
+
+        let response: InteractionResponse =
+            serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            response.kind(),
+            InteractionCallbackKind::ApplicationCommandAutocompleteResult
+        );
+        let data = match response.data() {
+            Some(InteractionCallbackData::Autocomplete(data)) => data,
+            other => panic!("expected autocomplete data, got {:?}", other),
+        };
+        assert_eq!(data.choices().len(), 3);
+    }
+
+    #[test]
+    fn serialize_option_min_max() {
+        let option = ApplicationCommandOption::builder()
+            .kind(ApplicationCommandOptionKind::Integer)
+            .name("count")
+            .description("How many")
+            .min_value(1)
+            .max_value(10)
+            .build();
+
+        let json = serde_json::to_value(&option).unwrap();
+
+        assert_eq!(
+            json,
+            json!({
+                "type": 4,
+                "name": "count",
+                "description": "How many",
+                "required": null,
+                "choices": null,
+                "options": null,
+                "min_value": 1,
+                "max_value": 10
+            })
+        );
+
+        let option = ApplicationCommandOption::builder()
+            .kind(ApplicationCommandOptionKind::Number)
+            .name("ratio")
+            .description("How much")
+            .min_value(0.5)
+            .autocomplete(true)
+            .build();
+
+        let json = serde_json::to_value(&option).unwrap();
+        assert_eq!(json["type"], json!(10));
+        assert_eq!(json["min_value"], json!(0.5));
+        assert_eq!(json["autocomplete"], json!(true));
+    }
+
+    #[test]
+    fn serialize_option_channel_types() {
+        let option = ApplicationCommandOption::builder()
+            .kind(ApplicationCommandOptionKind::Channel)
+            .name("channel")
+            .description("Where to post")
+            .channel_types(vec![ChannelKind::GuildText.into()])
+            .build();
+
+        let json = serde_json::to_value(&option).unwrap();
+
+        assert_eq!(json["type"], json!(7));
+        assert_eq!(json["channel_types"], json!([0]));
+        assert!(json.get("min_value").is_none());
+    }
+
+    #[test]
+    fn deserialize_option_constraints() {
+        let json = json!({
+            "type": 10,
+            "name": "ratio",
+            "description": "How much",
+            "min_value": 0.5,
+            "max_value": 2,
+            "autocomplete": false
+        });
+
+        let option: ApplicationCommandOption =
+            serde_json::from_value(json).unwrap();
+
+        assert_eq!(option.kind(), ApplicationCommandOptionKind::Number);
+        assert_eq!(option.min_value(), Some(OptionValue::Number(0.5)));
+        assert_eq!(option.max_value(), Some(OptionValue::Integer(2)));
+        assert_eq!(option.autocomplete(), Some(false));
+        assert!(option.channel_types().is_none());
+    }
+
     #[test]
     fn deserialize_application_command_permission_user() {
         let json = json!({
@@ -468,6 +1391,92 @@ mod tests {
         assert_eq!(acp.permission(), true);
     }
 
+    #[test]
+    fn deserialize_application_command_permission_channel() {
+        let json = json!({
+            "id": "172150183260323840",
+            "type": 3,
+            "permission": false,
+        });
+
+        let acp: ApplicationCommandPermission =
+            serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            acp.id(),
+            CommandPermissionId::Channel(172150183260323840.into())
+        );
+        assert!(acp.id().is_channel());
+        assert!(!acp.permission());
+    }
+
+    #[test]
+    fn deserialize_application_command_permission_unknown() {
+        let json = json!({
+            "id": "172150183260323840",
+            "type": 99,
+            "permission": true,
+        });
+
+        serde_json::from_value::<ApplicationCommandPermission>(json)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn serialize_application_command_permission_ids() {
+        let cases = [
+            (CommandPermissionId::Role(10.into()), 1),
+            (CommandPermissionId::User(20.into()), 2),
+            (CommandPermissionId::Channel(30.into()), 3),
+        ];
+
+        for (id, kind) in cases.iter() {
+            let acp = ApplicationCommandPermission::builder()
+                .id(*id)
+                .permission(true)
+                .build();
+
+            let json = serde_json::to_value(&acp).unwrap();
+            assert_eq!(json["type"], json!(kind));
+
+            let back: ApplicationCommandPermission =
+                serde_json::from_value(json).unwrap();
+            assert_eq!(back.id(), *id);
+        }
+    }
+
+    #[test]
+    fn command_permission_sentinels() {
+        let guild_id: GuildId = 41771983429143937.into();
+
+        let everyone = CommandPermissionId::everyone(guild_id);
+        assert_eq!(
+            everyone,
+            CommandPermissionId::Role(41771983429143937.into())
+        );
+        assert!(everyone.is_everyone(guild_id));
+        assert!(!everyone.is_all_channels(guild_id));
+
+        let all = CommandPermissionId::all_channels(guild_id).unwrap();
+        assert_eq!(all, CommandPermissionId::Channel(41771983429143936.into()));
+        assert!(all.is_all_channels(guild_id));
+        assert!(!all.is_everyone(guild_id));
+
+        let json = json!({
+            "id": "41771983429143936",
+            "type": 3,
+            "permission": true,
+        });
+        let acp: ApplicationCommandPermission =
+            serde_json::from_value(json).unwrap();
+        assert!(acp.id().is_all_channels(guild_id));
+
+        assert_eq!(CommandPermissionId::all_channels(0.into()), None);
+        assert!(
+            !CommandPermissionId::Channel(0.into()).is_all_channels(0.into())
+        );
+    }
+
     #[test]
     fn deserialize_guild_application_command_permissions() {
         let json = json!({
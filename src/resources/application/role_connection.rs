@@ -0,0 +1,158 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::enums::{EnumFromIntegerError, IntegerEnum};
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use typed_builder::TypedBuilder;
+
+/// How a user's metadata value is compared against a guild's configured
+/// value to grant a linked role.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum RoleConnectionMetadataKind {
+    IntegerLessThanOrEqual,
+    IntegerGreaterThanOrEqual,
+    IntegerEqual,
+    IntegerNotEqual,
+    DatetimeLessThanOrEqual,
+    DatetimeGreaterThanOrEqual,
+    BooleanEqual,
+    BooleanNotEqual,
+}
+
+impl TryFrom<u64> for RoleConnectionMetadataKind {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            1 => Self::IntegerLessThanOrEqual,
+            2 => Self::IntegerGreaterThanOrEqual,
+            3 => Self::IntegerEqual,
+            4 => Self::IntegerNotEqual,
+            5 => Self::DatetimeLessThanOrEqual,
+            6 => Self::DatetimeGreaterThanOrEqual,
+            7 => Self::BooleanEqual,
+            8 => Self::BooleanNotEqual,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<RoleConnectionMetadataKind> for u64 {
+    fn from(u: RoleConnectionMetadataKind) -> Self {
+        match u {
+            RoleConnectionMetadataKind::IntegerLessThanOrEqual => 1,
+            RoleConnectionMetadataKind::IntegerGreaterThanOrEqual => 2,
+            RoleConnectionMetadataKind::IntegerEqual => 3,
+            RoleConnectionMetadataKind::IntegerNotEqual => 4,
+            RoleConnectionMetadataKind::DatetimeLessThanOrEqual => 5,
+            RoleConnectionMetadataKind::DatetimeGreaterThanOrEqual => 6,
+            RoleConnectionMetadataKind::BooleanEqual => 7,
+            RoleConnectionMetadataKind::BooleanNotEqual => 8,
+        }
+    }
+}
+
+/// One field of the metadata an application reports for linked roles.
+#[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
+pub struct RoleConnectionMetadata {
+    #[builder(setter(into))]
+    #[serde(rename = "type")]
+    kind: IntegerEnum<RoleConnectionMetadataKind>,
+
+    /// Must be `a-z`, `0-9`, or `_`, and at most 50 characters.
+    #[builder(setter(into))]
+    key: String,
+
+    #[builder(setter(into))]
+    name: String,
+
+    #[builder(default, setter(strip_option, into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name_localizations: Option<HashMap<String, String>>,
+
+    #[builder(setter(into))]
+    description: String,
+
+    #[builder(default, setter(strip_option, into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description_localizations: Option<HashMap<String, String>>,
+}
+
+impl RoleConnectionMetadata {
+    pub fn try_kind(
+        &self,
+    ) -> Result<RoleConnectionMetadataKind, EnumFromIntegerError> {
+        self.kind.try_unwrap()
+    }
+
+    pub fn kind(&self) -> RoleConnectionMetadataKind {
+        self.kind.unwrap()
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Localized names, keyed by locale (like `de` or `en-US`).
+    pub fn name_localizations(&self) -> Option<&HashMap<String, String>> {
+        self.name_localizations.as_ref()
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Localized descriptions, keyed by locale (like `de` or `en-US`).
+    pub fn description_localizations(
+        &self,
+    ) -> Option<&HashMap<String, String>> {
+        self.description_localizations.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_role_connection_metadata() {
+        let json = json!({
+            "type": 2,
+            "key": "games_won",
+            "name": "Games Won",
+            "name_localizations": { "fr": "Parties gagnées" },
+            "description": "Number of games won",
+            "description_localizations": null
+        });
+
+        let metadata: RoleConnectionMetadata =
+            serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            metadata.kind(),
+            RoleConnectionMetadataKind::IntegerGreaterThanOrEqual
+        );
+        assert_eq!(metadata.key(), "games_won");
+        assert_eq!(metadata.name(), "Games Won");
+        assert_eq!(
+            metadata.name_localizations().unwrap()["fr"],
+            "Parties gagnées"
+        );
+        assert_eq!(metadata.description(), "Number of games won");
+        assert_eq!(metadata.description_localizations(), None);
+    }
+}
@@ -47,6 +47,7 @@ pub use self::error::FromRawAuditLogChangeError;
 use serde::{Deserialize, Serialize};
 
 use std::convert::TryFrom;
+use std::num::ParseIntError;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -105,6 +106,9 @@ pub struct AuditEntryInfo {
     #[serde(rename = "type")]
     kind: Option<StringEnum<EntityKind>>,
     role_name: Option<String>,
+    auto_moderation_rule_name: Option<String>,
+    #[serde(rename = "auto_moderation_rule_trigger_type")]
+    auto_moderation_rule_trigger_kind: Option<String>,
 }
 
 impl AuditEntryInfo {
@@ -112,10 +116,18 @@ impl AuditEntryInfo {
         self.delete_member_days.as_deref()
     }
 
+    pub fn delete_member_days_u64(&self) -> Option<Result<u64, ParseIntError>> {
+        self.delete_member_days.as_deref().map(str::parse)
+    }
+
     pub fn members_removed(&self) -> Option<&str> {
         self.members_removed.as_deref()
     }
 
+    pub fn members_removed_u64(&self) -> Option<Result<u64, ParseIntError>> {
+        self.members_removed.as_deref().map(str::parse)
+    }
+
     pub fn channel_id(&self) -> Option<ChannelId> {
         self.channel_id
     }
@@ -128,6 +140,10 @@ impl AuditEntryInfo {
         self.count.as_deref()
     }
 
+    pub fn count_u64(&self) -> Option<Result<u64, ParseIntError>> {
+        self.count.as_deref().map(str::parse)
+    }
+
     pub fn id(&self) -> Option<AnyId> {
         self.id
     }
@@ -143,6 +159,17 @@ impl AuditEntryInfo {
     pub fn role_name(&self) -> Option<&str> {
         self.role_name.as_deref()
     }
+
+    /// The name of the auto-moderation rule that was triggered.
+    pub fn auto_moderation_rule_name(&self) -> Option<&str> {
+        self.auto_moderation_rule_name.as_deref()
+    }
+
+    /// The trigger kind of the auto-moderation rule that was triggered, as
+    /// the string Discord sends, like `"1"`.
+    pub fn auto_moderation_rule_trigger_kind(&self) -> Option<&str> {
+        self.auto_moderation_rule_trigger_kind.as_deref()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -520,6 +547,31 @@ pub enum AuditLogEvent {
     IntegrationCreate,
     IntegrationUpdate,
     IntegrationDelete,
+
+    StageInstanceCreate,
+    StageInstanceUpdate,
+    StageInstanceDelete,
+
+    StickerCreate,
+    StickerUpdate,
+    StickerDelete,
+
+    GuildScheduledEventCreate,
+    GuildScheduledEventUpdate,
+    GuildScheduledEventDelete,
+
+    ThreadCreate,
+    ThreadUpdate,
+    ThreadDelete,
+
+    ApplicationCommandPermissionUpdate,
+
+    AutoModerationRuleCreate,
+    AutoModerationRuleUpdate,
+    AutoModerationRuleDelete,
+    AutoModerationBlockMessage,
+    AutoModerationFlagToChannel,
+    AutoModerationUserCommunicationDisabled,
 }
 
 impl TryFrom<u64> for AuditLogEvent {
@@ -571,6 +623,31 @@ impl TryFrom<u64> for AuditLogEvent {
             81 => AuditLogEvent::IntegrationUpdate,
             82 => AuditLogEvent::IntegrationDelete,
 
+            83 => AuditLogEvent::StageInstanceCreate,
+            84 => AuditLogEvent::StageInstanceUpdate,
+            85 => AuditLogEvent::StageInstanceDelete,
+
+            90 => AuditLogEvent::StickerCreate,
+            91 => AuditLogEvent::StickerUpdate,
+            92 => AuditLogEvent::StickerDelete,
+
+            100 => AuditLogEvent::GuildScheduledEventCreate,
+            101 => AuditLogEvent::GuildScheduledEventUpdate,
+            102 => AuditLogEvent::GuildScheduledEventDelete,
+
+            110 => AuditLogEvent::ThreadCreate,
+            111 => AuditLogEvent::ThreadUpdate,
+            112 => AuditLogEvent::ThreadDelete,
+
+            121 => AuditLogEvent::ApplicationCommandPermissionUpdate,
+
+            140 => AuditLogEvent::AutoModerationRuleCreate,
+            141 => AuditLogEvent::AutoModerationRuleUpdate,
+            142 => AuditLogEvent::AutoModerationRuleDelete,
+            143 => AuditLogEvent::AutoModerationBlockMessage,
+            144 => AuditLogEvent::AutoModerationFlagToChannel,
+            145 => AuditLogEvent::AutoModerationUserCommunicationDisabled,
+
             other => return Err(EnumFromIntegerError::new(other)),
         };
 
@@ -624,6 +701,31 @@ impl From<AuditLogEvent> for u64 {
             AuditLogEvent::IntegrationCreate => 80,
             AuditLogEvent::IntegrationUpdate => 81,
             AuditLogEvent::IntegrationDelete => 82,
+
+            AuditLogEvent::StageInstanceCreate => 83,
+            AuditLogEvent::StageInstanceUpdate => 84,
+            AuditLogEvent::StageInstanceDelete => 85,
+
+            AuditLogEvent::StickerCreate => 90,
+            AuditLogEvent::StickerUpdate => 91,
+            AuditLogEvent::StickerDelete => 92,
+
+            AuditLogEvent::GuildScheduledEventCreate => 100,
+            AuditLogEvent::GuildScheduledEventUpdate => 101,
+            AuditLogEvent::GuildScheduledEventDelete => 102,
+
+            AuditLogEvent::ThreadCreate => 110,
+            AuditLogEvent::ThreadUpdate => 111,
+            AuditLogEvent::ThreadDelete => 112,
+
+            AuditLogEvent::ApplicationCommandPermissionUpdate => 121,
+
+            AuditLogEvent::AutoModerationRuleCreate => 140,
+            AuditLogEvent::AutoModerationRuleUpdate => 141,
+            AuditLogEvent::AutoModerationRuleDelete => 142,
+            AuditLogEvent::AutoModerationBlockMessage => 143,
+            AuditLogEvent::AutoModerationFlagToChannel => 144,
+            AuditLogEvent::AutoModerationUserCommunicationDisabled => 145,
         }
     }
 }
@@ -726,9 +828,37 @@ impl AuditLog {
         &self.audit_log_entries
     }
 
+    pub(crate) fn into_audit_log_entries(self) -> Vec<AuditLogEntry> {
+        self.audit_log_entries
+    }
+
     pub fn integrations(&self) -> &[AuditLogIntegration] {
         &self.integrations
     }
+
+    /// Look up a user included in this audit log.
+    pub fn user(&self, id: UserId) -> Option<&User> {
+        self.users.iter().find(|u| u.id() == id)
+    }
+
+    /// The entries for `event`, each with the entry's target and executor
+    /// resolved against [`AuditLog::users`].
+    ///
+    /// The target is only resolved when it is a user included in the log.
+    pub fn entries_for(
+        &self,
+        event: AuditLogEvent,
+    ) -> impl Iterator<Item = (&AuditLogEntry, Option<&User>, Option<&User>)>
+    {
+        self.audit_log_entries
+            .iter()
+            .filter(move |e| e.try_action_kind() == Ok(event))
+            .map(move |e| {
+                let target = e.target_id().and_then(|id| self.user(id.into()));
+                let executor = e.user_id().and_then(|id| self.user(id));
+                (e, target, executor)
+            })
+    }
 }
 
 #[cfg(test)]
@@ -773,9 +903,8 @@ mod tests {
         assert_eq!(int.account().id(), 1234567.into());
     }
 
-    #[test]
-    fn deserialize_audit_log() {
-        let json = json!({
+    fn audit_log_json() -> serde_json::Value {
+        json!({
             "audit_log_entries": [
             {
                 "action_type": 31,
@@ -995,8 +1124,127 @@ mod tests {
             }
             ],
             "webhooks": []
+        })
+    }
+
+    #[test]
+    fn audit_entry_info_prune_numbers() {
+        let json = json!({
+            "delete_member_days": "7",
+            "members_removed": "12"
         });
 
+        let info: AuditEntryInfo = serde_json::from_value(json).unwrap();
+
+        assert_eq!(info.delete_member_days_u64(), Some(Ok(7)));
+        assert_eq!(info.members_removed_u64(), Some(Ok(12)));
+        assert_eq!(info.count_u64(), None);
+    }
+
+    #[test]
+    fn audit_entry_info_bad_number() {
+        let json = json!({ "count": "many" });
+
+        let info: AuditEntryInfo = serde_json::from_value(json).unwrap();
+
+        assert_eq!(info.count(), Some("many"));
+        assert!(info.count_u64().unwrap().is_err());
+    }
+
+    #[test]
+    fn audit_entry_info_auto_moderation() {
+        let json = json!({
+            "channel_id": "41771983423143937",
+            "auto_moderation_rule_name": "Keyword Filter 1",
+            "auto_moderation_rule_trigger_type": "1"
+        });
+
+        let info: AuditEntryInfo = serde_json::from_value(json).unwrap();
+
+        assert_eq!(info.auto_moderation_rule_name(), Some("Keyword Filter 1"));
+        assert_eq!(info.auto_moderation_rule_trigger_kind(), Some("1"));
+    }
+
+    #[test]
+    fn deserialize_audit_log_change_unknown_expire_behavior() {
+        let json = json!({
+            "action_type": 81,
+            "changes": [
+                {
+                    "key": "expire_behavior",
+                    "old_value": 0,
+                    "new_value": 2
+                }
+            ],
+            "id": "843340110657777777",
+            "target_id": "33590653072239123",
+            "user_id": "843299027126666666"
+        });
+
+        let entry: AuditLogEntry = serde_json::from_value(json).unwrap();
+        let changes = entry.changes().unwrap();
+
+        let values = match &changes[0] {
+            AuditLogChange::ExpireBehavior(values) => values,
+            other => panic!("unexpected change: {:?}", other),
+        };
+
+        assert_eq!(
+            values.old.unwrap().try_unwrap(),
+            Ok(IntegrationExpireBehavior::RemoveRole)
+        );
+        assert_eq!(values.new.unwrap().try_unwrap().unwrap_err().inner(), 2);
+    }
+
+    #[test]
+    fn deserialize_audit_log_entry_thread_create() {
+        let json = json!({
+            "action_type": 110,
+            "changes": [
+                {
+                    "key": "name",
+                    "new_value": "a thread"
+                }
+            ],
+            "id": "843340110657777777",
+            "target_id": "843300000000000001",
+            "user_id": "843299027126666666"
+        });
+
+        let entry: AuditLogEntry = serde_json::from_value(json).unwrap();
+
+        assert_eq!(entry.try_action_kind(), Ok(AuditLogEvent::ThreadCreate));
+        assert_eq!(u64::from(AuditLogEvent::ThreadCreate), 110);
+    }
+
+    #[test]
+    fn audit_log_entries_for() {
+        let log: AuditLog = serde_json::from_value(audit_log_json()).unwrap();
+
+        let pins: Vec<_> = log.entries_for(AuditLogEvent::MessagePin).collect();
+        assert_eq!(pins.len(), 1);
+
+        let (entry, target, executor) = pins[0];
+        assert_eq!(entry.id(), 843340438576666666.into());
+        assert_eq!(target.unwrap().username(), "discord-next-testing");
+        assert_eq!(executor.unwrap().username(), "discord-next-testing");
+
+        let updates: Vec<_> =
+            log.entries_for(AuditLogEvent::ChannelUpdate).collect();
+        assert_eq!(updates.len(), 1);
+
+        let (entry, target, executor) = updates[0];
+        assert_eq!(entry.changes().unwrap().len(), 2);
+        assert!(target.is_none());
+        assert_eq!(executor.unwrap().id(), 843299027126666666.into());
+
+        assert_eq!(log.entries_for(AuditLogEvent::GuildUpdate).count(), 0);
+    }
+
+    #[test]
+    fn deserialize_audit_log() {
+        let json = audit_log_json();
+
         let log: AuditLog = serde_json::from_value(json).unwrap();
 
         let entries = log.audit_log_entries();
@@ -0,0 +1,551 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::enums::{EnumFromIntegerError, IntegerEnum};
+use crate::permissions::RoleId;
+use crate::resources::channel::ChannelId;
+use crate::resources::guild::GuildId;
+use crate::resources::user::UserId;
+use crate::snowflake::Id;
+
+use serde::{Deserialize, Serialize};
+
+use std::convert::TryFrom;
+
+use typed_builder::TypedBuilder;
+
+pub type AutoModerationRuleId = Id<AutoModerationRule>;
+
+/// When a rule is checked.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum AutoModerationEventKind {
+    /// When a member sends or edits a message.
+    MessageSend,
+
+    /// When a member edits their profile.
+    MemberUpdate,
+}
+
+impl TryFrom<u64> for AutoModerationEventKind {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            1 => Self::MessageSend,
+            2 => Self::MemberUpdate,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<AutoModerationEventKind> for u64 {
+    fn from(u: AutoModerationEventKind) -> Self {
+        match u {
+            AutoModerationEventKind::MessageSend => 1,
+            AutoModerationEventKind::MemberUpdate => 2,
+        }
+    }
+}
+
+/// What a rule looks for.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum AutoModerationTriggerKind {
+    /// Content containing words or patterns from a user-defined list.
+    Keyword,
+
+    /// Content that Discord considers spam.
+    Spam,
+
+    /// Content containing words from Discord's predefined lists.
+    KeywordPreset,
+
+    /// Content with too many unique role and user mentions.
+    MentionSpam,
+
+    /// Member profiles containing words from a user-defined list.
+    MemberProfile,
+}
+
+impl TryFrom<u64> for AutoModerationTriggerKind {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            1 => Self::Keyword,
+            3 => Self::Spam,
+            4 => Self::KeywordPreset,
+            5 => Self::MentionSpam,
+            6 => Self::MemberProfile,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<AutoModerationTriggerKind> for u64 {
+    fn from(u: AutoModerationTriggerKind) -> Self {
+        match u {
+            AutoModerationTriggerKind::Keyword => 1,
+            AutoModerationTriggerKind::Spam => 3,
+            AutoModerationTriggerKind::KeywordPreset => 4,
+            AutoModerationTriggerKind::MentionSpam => 5,
+            AutoModerationTriggerKind::MemberProfile => 6,
+        }
+    }
+}
+
+/// A word list maintained by Discord, for
+/// [`AutoModerationTriggerKind::KeywordPreset`] rules.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum KeywordPresetKind {
+    Profanity,
+    SexualContent,
+    Slurs,
+}
+
+impl TryFrom<u64> for KeywordPresetKind {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            1 => Self::Profanity,
+            2 => Self::SexualContent,
+            3 => Self::Slurs,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<KeywordPresetKind> for u64 {
+    fn from(u: KeywordPresetKind) -> Self {
+        match u {
+            KeywordPresetKind::Profanity => 1,
+            KeywordPresetKind::SexualContent => 2,
+            KeywordPresetKind::Slurs => 3,
+        }
+    }
+}
+
+/// Extra configuration for a rule's trigger. Which fields apply depends on
+/// the rule's [`AutoModerationTriggerKind`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TypedBuilder)]
+pub struct TriggerMetadata {
+    #[builder(default, setter(strip_option, into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keyword_filter: Option<Vec<String>>,
+
+    #[builder(default, setter(strip_option, into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    regex_patterns: Option<Vec<String>>,
+
+    #[builder(default, setter(strip_option, into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    presets: Option<Vec<IntegerEnum<KeywordPresetKind>>>,
+
+    #[builder(default, setter(strip_option, into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_list: Option<Vec<String>>,
+
+    #[builder(default, setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mention_total_limit: Option<u64>,
+
+    #[builder(default, setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mention_raid_protection_enabled: Option<bool>,
+}
+
+impl TriggerMetadata {
+    pub fn keyword_filter(&self) -> Option<&[String]> {
+        self.keyword_filter.as_deref()
+    }
+
+    pub fn regex_patterns(&self) -> Option<&[String]> {
+        self.regex_patterns.as_deref()
+    }
+
+    /// The preset lists to match against. Empty unless the rule's trigger is
+    /// [`AutoModerationTriggerKind::KeywordPreset`].
+    pub fn try_presets(
+        &self,
+    ) -> impl Iterator<Item = &IntegerEnum<KeywordPresetKind>> {
+        self.presets.iter().flatten()
+    }
+
+    pub fn presets(&self) -> impl Iterator<Item = KeywordPresetKind> + '_ {
+        self.presets.iter().flatten().map(|x| x.unwrap())
+    }
+
+    /// Words that never trigger the rule.
+    pub fn allow_list(&self) -> Option<&[String]> {
+        self.allow_list.as_deref()
+    }
+
+    pub fn mention_total_limit(&self) -> Option<u64> {
+        self.mention_total_limit
+    }
+
+    pub fn mention_raid_protection_enabled(&self) -> Option<bool> {
+        self.mention_raid_protection_enabled
+    }
+}
+
+/// What happens when a rule is triggered.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum AutoModerationActionKind {
+    BlockMessage,
+    SendAlertMessage,
+    Timeout,
+    BlockMemberInteraction,
+}
+
+impl TryFrom<u64> for AutoModerationActionKind {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            1 => Self::BlockMessage,
+            2 => Self::SendAlertMessage,
+            3 => Self::Timeout,
+            4 => Self::BlockMemberInteraction,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<AutoModerationActionKind> for u64 {
+    fn from(u: AutoModerationActionKind) -> Self {
+        match u {
+            AutoModerationActionKind::BlockMessage => 1,
+            AutoModerationActionKind::SendAlertMessage => 2,
+            AutoModerationActionKind::Timeout => 3,
+            AutoModerationActionKind::BlockMemberInteraction => 4,
+        }
+    }
+}
+
+/// Extra configuration for an action. Which fields apply depends on the
+/// action's [`AutoModerationActionKind`].
+#[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
+pub struct ActionMetadata {
+    /// Where to send alerts.
+    #[builder(default, setter(strip_option, into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<ChannelId>,
+
+    /// How long to time out the member for, up to four weeks.
+    #[builder(default, setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration_seconds: Option<u64>,
+
+    /// Shown to the member when their message is blocked.
+    #[builder(default, setter(strip_option, into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_message: Option<String>,
+}
+
+impl ActionMetadata {
+    pub fn channel_id(&self) -> Option<ChannelId> {
+        self.channel_id
+    }
+
+    pub fn duration_seconds(&self) -> Option<u64> {
+        self.duration_seconds
+    }
+
+    pub fn custom_message(&self) -> Option<&str> {
+        self.custom_message.as_deref()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
+pub struct AutoModerationAction {
+    #[builder(setter(into))]
+    #[serde(rename = "type")]
+    kind: IntegerEnum<AutoModerationActionKind>,
+
+    #[builder(default, setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<ActionMetadata>,
+}
+
+impl AutoModerationAction {
+    pub fn try_kind(
+        &self,
+    ) -> Result<AutoModerationActionKind, EnumFromIntegerError> {
+        self.kind.try_unwrap()
+    }
+
+    pub fn kind(&self) -> AutoModerationActionKind {
+        self.kind.unwrap()
+    }
+
+    pub fn metadata(&self) -> Option<&ActionMetadata> {
+        self.metadata.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoModerationRule {
+    id: AutoModerationRuleId,
+    guild_id: GuildId,
+    name: String,
+    creator_id: UserId,
+    #[serde(rename = "event_type")]
+    event_kind: IntegerEnum<AutoModerationEventKind>,
+    #[serde(rename = "trigger_type")]
+    trigger_kind: IntegerEnum<AutoModerationTriggerKind>,
+    trigger_metadata: TriggerMetadata,
+    actions: Vec<AutoModerationAction>,
+    enabled: bool,
+    exempt_roles: Vec<RoleId>,
+    exempt_channels: Vec<ChannelId>,
+}
+
+impl AutoModerationRule {
+    pub fn id(&self) -> AutoModerationRuleId {
+        self.id
+    }
+
+    pub fn guild_id(&self) -> GuildId {
+        self.guild_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn creator_id(&self) -> UserId {
+        self.creator_id
+    }
+
+    pub fn try_event_kind(
+        &self,
+    ) -> Result<AutoModerationEventKind, EnumFromIntegerError> {
+        self.event_kind.try_unwrap()
+    }
+
+    pub fn event_kind(&self) -> AutoModerationEventKind {
+        self.event_kind.unwrap()
+    }
+
+    pub fn try_trigger_kind(
+        &self,
+    ) -> Result<AutoModerationTriggerKind, EnumFromIntegerError> {
+        self.trigger_kind.try_unwrap()
+    }
+
+    pub fn trigger_kind(&self) -> AutoModerationTriggerKind {
+        self.trigger_kind.unwrap()
+    }
+
+    pub fn trigger_metadata(&self) -> &TriggerMetadata {
+        &self.trigger_metadata
+    }
+
+    pub fn actions(&self) -> &[AutoModerationAction] {
+        &self.actions
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Members with any of these roles aren't affected by the rule.
+    pub fn exempt_roles(&self) -> &[RoleId] {
+        &self.exempt_roles
+    }
+
+    /// Messages in these channels aren't affected by the rule.
+    pub fn exempt_channels(&self) -> &[ChannelId] {
+        &self.exempt_channels
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct NewAutoModerationRule {
+    pub(crate) name: String,
+
+    #[serde(rename = "event_type")]
+    pub(crate) event_kind: IntegerEnum<AutoModerationEventKind>,
+
+    #[serde(rename = "trigger_type")]
+    pub(crate) trigger_kind: IntegerEnum<AutoModerationTriggerKind>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) trigger_metadata: Option<TriggerMetadata>,
+
+    pub(crate) actions: Vec<AutoModerationAction>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) enabled: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) exempt_roles: Option<Vec<RoleId>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) exempt_channels: Option<Vec<ChannelId>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct EditAutoModerationRule {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(rename = "event_type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) event_kind: Option<IntegerEnum<AutoModerationEventKind>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) trigger_metadata: Option<TriggerMetadata>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) actions: Option<Vec<AutoModerationAction>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) enabled: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) exempt_roles: Option<Vec<RoleId>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) exempt_channels: Option<Vec<ChannelId>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::round_trip::assert_round_trip;
+
+    use serde_json::json;
+
+    use super::*;
+
+    fn keyword_rule_json() -> serde_json::Value {
+        json!({
+            "id": "969707018069872670",
+            "guild_id": "613425648685547541",
+            "name": "Keyword Filter 1",
+            "creator_id": "423457898095789043",
+            "trigger_type": 1,
+            "event_type": 1,
+            "actions": [
+                {
+                    "type": 1,
+                    "metadata": { "custom_message": "Please keep it clean" }
+                },
+                {
+                    "type": 2,
+                    "metadata": { "channel_id": "123456789123456789" }
+                }
+            ],
+            "trigger_metadata": {
+                "keyword_filter": ["cat*", "*dog", "*ana*", "i like c++"],
+                "regex_patterns": ["(b|c)at", "^(?:[0-9]{1,3}\\.){3}[0-9]{1,3}$"]
+            },
+            "enabled": true,
+            "exempt_roles": ["323456789123456789", "423456789123456789"],
+            "exempt_channels": ["523456789123456789"]
+        })
+    }
+
+    #[test]
+    fn deserialize_keyword_rule() {
+        let rule: AutoModerationRule =
+            serde_json::from_value(keyword_rule_json()).unwrap();
+
+        assert_eq!(rule.id(), 969707018069872670.into());
+        assert_eq!(rule.guild_id(), 613425648685547541.into());
+        assert_eq!(rule.name(), "Keyword Filter 1");
+        assert_eq!(rule.creator_id(), 423457898095789043.into());
+        assert_eq!(rule.trigger_kind(), AutoModerationTriggerKind::Keyword);
+        assert_eq!(rule.event_kind(), AutoModerationEventKind::MessageSend);
+        assert!(rule.enabled());
+
+        let metadata = rule.trigger_metadata();
+        assert_eq!(
+            metadata.keyword_filter().unwrap(),
+            ["cat*", "*dog", "*ana*", "i like c++"]
+        );
+        assert_eq!(metadata.regex_patterns().unwrap().len(), 2);
+        assert_eq!(metadata.presets().count(), 0);
+
+        let actions = rule.actions();
+        assert_eq!(actions[0].kind(), AutoModerationActionKind::BlockMessage);
+        assert_eq!(
+            actions[0].metadata().unwrap().custom_message(),
+            Some("Please keep it clean")
+        );
+        assert_eq!(
+            actions[1].kind(),
+            AutoModerationActionKind::SendAlertMessage
+        );
+        assert_eq!(
+            actions[1].metadata().unwrap().channel_id(),
+            Some(123456789123456789.into())
+        );
+
+        assert_eq!(
+            rule.exempt_roles(),
+            [323456789123456789.into(), 423456789123456789.into()]
+        );
+        assert_eq!(rule.exempt_channels(), [523456789123456789.into()]);
+    }
+
+    #[test]
+    fn keyword_rule_round_trip() {
+        assert_round_trip::<AutoModerationRule>(keyword_rule_json());
+    }
+
+    #[test]
+    fn serialize_new_rule() {
+        let rule = NewAutoModerationRule {
+            name: "No presets".into(),
+            event_kind: AutoModerationEventKind::MessageSend.into(),
+            trigger_kind: AutoModerationTriggerKind::KeywordPreset.into(),
+            trigger_metadata: Some(
+                TriggerMetadata::builder()
+                    .presets(vec![
+                        KeywordPresetKind::Profanity.into(),
+                        KeywordPresetKind::Slurs.into(),
+                    ])
+                    .allow_list(vec!["heck".to_owned()])
+                    .build(),
+            ),
+            actions: vec![AutoModerationAction::builder()
+                .kind(AutoModerationActionKind::Timeout)
+                .metadata(
+                    ActionMetadata::builder().duration_seconds(60).build(),
+                )
+                .build()],
+            enabled: Some(true),
+            exempt_roles: None,
+            exempt_channels: None,
+        };
+
+        let json = serde_json::to_value(&rule).unwrap();
+
+        let expected = json!({
+            "name": "No presets",
+            "event_type": 1,
+            "trigger_type": 4,
+            "trigger_metadata": {
+                "presets": [1, 3],
+                "allow_list": ["heck"]
+            },
+            "actions": [
+                { "type": 3, "metadata": { "duration_seconds": 60 } }
+            ],
+            "enabled": true
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -2,6 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+mod component;
 mod embed;
 mod message;
 
@@ -17,8 +18,10 @@ use crate::permissions::{Permissions, RoleId};
 use crate::resources::application::ApplicationId;
 use crate::resources::guild::GuildId;
 use crate::resources::user::{User, UserId};
+use crate::resources::webhook::WebhookId;
 use crate::snowflake::Id;
 
+pub use self::component::*;
 pub use self::embed::*;
 pub use self::message::*;
 
@@ -35,6 +38,8 @@ pub struct ThreadMetadata {
     auto_archive_duration: u64,
     archive_timestamp: DateTime<FixedOffset>,
     locked: Option<bool>,
+    invitable: Option<bool>,
+    create_timestamp: Option<DateTime<FixedOffset>>,
 }
 
 impl ThreadMetadata {
@@ -57,6 +62,18 @@ impl ThreadMetadata {
     pub fn locked(&self) -> Option<bool> {
         self.locked
     }
+
+    /// Whether non-moderators can add other non-moderators to a private
+    /// thread.
+    pub fn invitable(&self) -> Option<bool> {
+        self.invitable
+    }
+
+    /// When the thread was created. Only present for threads created after
+    /// 2022-01-09.
+    pub fn create_timestamp(&self) -> Option<DateTime<FixedOffset>> {
+        self.create_timestamp
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -92,6 +109,10 @@ impl ThreadMember {
 bitflags! {
     pub struct ThreadMemberFlags: u64 {
         const NONE = 0;
+        const HAS_INTERACTED = 1 << 0;
+        const ALL_MESSAGES = 1 << 1;
+        const ONLY_MENTIONS = 1 << 2;
+        const NO_MESSAGES = 1 << 3;
     }
 }
 
@@ -156,6 +177,7 @@ impl From<OverwriteId> for OverwriteIdHelper {
                 id: u64::from(uid).into(),
                 kind: 1,
             },
+            OverwriteId::Other { id, kind } => Self { id, kind },
         }
     }
 }
@@ -169,7 +191,7 @@ impl From<OverwriteIdHelper> for OverwriteId {
             OverwriteIdHelper { id, kind: 1 } => {
                 Self::Member(u64::from(id).into())
             }
-            _ => panic!("unsupported overwrite id"),
+            OverwriteIdHelper { id, kind } => Self::Other { id, kind },
         }
     }
 }
@@ -179,6 +201,13 @@ impl From<OverwriteIdHelper> for OverwriteId {
 pub enum OverwriteId {
     Role(RoleId),
     Member(UserId),
+
+    /// An overwrite `type` this crate doesn't know about yet, kept so the rest
+    /// of the channel still parses.
+    Other {
+        id: Id<()>,
+        kind: u64,
+    },
 }
 
 impl OverwriteId {
@@ -217,6 +246,14 @@ pub struct Overwrite {
 }
 
 impl Overwrite {
+    pub fn new(id: OverwriteId, allow: Permissions, deny: Permissions) -> Self {
+        Self {
+            id,
+            allow: allow.into(),
+            deny: deny.into(),
+        }
+    }
+
     pub fn id(&self) -> OverwriteId {
         self.id
     }
@@ -250,6 +287,8 @@ pub enum ChannelKind {
     GroupDm,
     GuildCategory,
     GuildNews,
+    /// No longer supported by Discord, and can't be created, but existing
+    /// store channels may still be returned.
     GuildStore,
     GuildNewsThread,
     GuildPublicThread,
@@ -257,6 +296,28 @@ pub enum ChannelKind {
     GuildStageVoice,
 }
 
+impl ChannelKind {
+    /// News, public, and private threads.
+    pub fn is_thread(self) -> bool {
+        matches!(
+            self,
+            Self::GuildNewsThread
+                | Self::GuildPublicThread
+                | Self::GuildPrivateThread
+        )
+    }
+
+    /// Channels users connect to with voice: voice and stage channels.
+    pub fn is_voice(self) -> bool {
+        matches!(self, Self::GuildVoice | Self::GuildStageVoice)
+    }
+
+    /// Direct messages, with one user or a group.
+    pub fn is_dm(self) -> bool {
+        matches!(self, Self::Dm | Self::GroupDm)
+    }
+}
+
 impl From<ChannelKind> for u64 {
     fn from(u: ChannelKind) -> Self {
         match u {
@@ -350,6 +411,105 @@ pub(crate) struct EditChannel {
     pub(crate) locked: Option<bool>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct EditGroupDm {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) icon: Option<UploadImage>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct NewChannel {
+    pub(crate) name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type")]
+    pub(crate) kind: Option<IntegerEnum<ChannelKind>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) topic: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) bitrate: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) user_limit: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) rate_limit_per_user: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) position: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) permission_overwrites: Option<Vec<Overwrite>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parent_id: Option<ChannelId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) nsfw: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) rtc_region: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) video_quality_mode: Option<IntegerEnum<VideoQualityMode>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct NewDm {
+    pub(crate) recipient_id: UserId,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct NewChannelFollower {
+    pub(crate) webhook_channel_id: ChannelId,
+}
+
+/// A page of threads, with the current user's membership in each.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadList {
+    threads: Vec<Channel>,
+    members: Vec<ThreadMember>,
+    has_more: bool,
+}
+
+impl ThreadList {
+    pub fn threads(&self) -> &[Channel] {
+        &self.threads
+    }
+
+    /// The current user's membership in each of [`ThreadList::threads`] that
+    /// they have joined.
+    pub fn members(&self) -> &[ThreadMember] {
+        &self.members
+    }
+
+    /// Whether there are more threads to fetch.
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FollowedChannel {
+    channel_id: ChannelId,
+    webhook_id: WebhookId,
+}
+
+impl FollowedChannel {
+    pub fn channel_id(&self) -> ChannelId {
+        self.channel_id
+    }
+
+    pub fn webhook_id(&self) -> WebhookId {
+        self.webhook_id
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Channel {
     id: ChannelId,
@@ -384,6 +544,11 @@ impl Channel {
         self.id
     }
 
+    /// Text that renders as a link to this channel, like `<#41771983423143937>`.
+    pub fn mention(&self) -> String {
+        self.id.mention().to_string()
+    }
+
     pub fn try_kind(
         &self,
     ) -> Option<Result<ChannelKind, EnumFromIntegerError>> {
@@ -394,6 +559,14 @@ impl Channel {
         self.kind.map(IntegerEnum::unwrap)
     }
 
+    /// Whether this channel is a news, public, or private thread.
+    pub fn is_thread(&self) -> bool {
+        matches!(
+            self.kind.map(IntegerEnum::try_unwrap),
+            Some(Ok(kind)) if kind.is_thread()
+        )
+    }
+
     pub fn guild_id(&self) -> Option<GuildId> {
         self.guild_id
     }
@@ -490,6 +663,7 @@ mod tests {
     use chrono::{TimeZone, Utc};
 
     use crate::image::Image;
+    use crate::round_trip::assert_round_trip;
 
     use serde_json::json;
 
@@ -520,6 +694,52 @@ mod tests {
         assert_eq!(channel.parent_id(), None);
     }
 
+    #[test]
+    fn channel_kind_classification() {
+        let all = [
+            ChannelKind::GuildText,
+            ChannelKind::Dm,
+            ChannelKind::GuildVoice,
+            ChannelKind::GroupDm,
+            ChannelKind::GuildCategory,
+            ChannelKind::GuildNews,
+            ChannelKind::GuildStore,
+            ChannelKind::GuildNewsThread,
+            ChannelKind::GuildPublicThread,
+            ChannelKind::GuildPrivateThread,
+            ChannelKind::GuildStageVoice,
+        ];
+
+        let threads: Vec<_> = all.iter().filter(|k| k.is_thread()).collect();
+        assert_eq!(
+            threads,
+            [
+                &ChannelKind::GuildNewsThread,
+                &ChannelKind::GuildPublicThread,
+                &ChannelKind::GuildPrivateThread,
+            ]
+        );
+
+        let voice: Vec<_> = all.iter().filter(|k| k.is_voice()).collect();
+        assert_eq!(
+            voice,
+            [&ChannelKind::GuildVoice, &ChannelKind::GuildStageVoice]
+        );
+
+        let dms: Vec<_> = all.iter().filter(|k| k.is_dm()).collect();
+        assert_eq!(dms, [&ChannelKind::Dm, &ChannelKind::GroupDm]);
+
+        for kind in all.iter() {
+            let channel: Channel = serde_json::from_value(json!({
+                "id": "41771983423143937",
+                "type": u64::from(*kind),
+            }))
+            .unwrap();
+
+            assert_eq!(channel.is_thread(), kind.is_thread(), "{:?}", kind);
+        }
+    }
+
     #[test]
     fn channel_deserialize_category() {
         let json = json!({
@@ -713,9 +933,8 @@ mod tests {
         assert_eq!(channel.topic(), Some("Rumors about Half Life 3"));
     }
 
-    #[test]
-    fn channel_deserialize_text() {
-        let json = json!({
+    fn text_channel_json() -> serde_json::Value {
+        json!({
             "id": "41771983423143937",
             "guild_id": "41771983423143937",
             "name": "general",
@@ -727,7 +946,17 @@ mod tests {
             "topic": "24/7 chat about how to gank Mike #2",
             "last_message_id": "155117677105512449",
             "parent_id": "399942396007890945"
-        });
+        })
+    }
+
+    #[test]
+    fn channel_round_trip() {
+        assert_round_trip::<Channel>(text_channel_json());
+    }
+
+    #[test]
+    fn channel_deserialize_text() {
+        let json = text_channel_json();
 
         let channel: Channel = serde_json::from_value(json).unwrap();
 
@@ -755,8 +984,79 @@ mod tests {
     }
 
     #[test]
-    fn message_deserialize() {
+    fn channel_deserialize_minimal() {
+        let json = json!({ "id": "41771983423143937" });
+
+        let channel: Channel = serde_json::from_value(json).unwrap();
+
+        assert_eq!(channel.id(), 41771983423143937.into());
+        assert_eq!(channel.mention(), "<#41771983423143937>");
+        assert_eq!(channel.kind(), None);
+        assert!(channel.try_kind().is_none());
+        assert_eq!(channel.guild_id(), None);
+        assert_eq!(channel.name(), None);
+        assert!(channel.permission_overwrites().is_none());
+        assert!(channel.thread_metadata().is_none());
+        assert!(channel.member().is_none());
+    }
+
+    #[test]
+    fn channel_deserialize_private_thread() {
         let json = json!({
+            "id": "41771983423143937",
+            "guild_id": "41771983423143936",
+            "parent_id": "41771983423143935",
+            "owner_id": "41771983423143934",
+            "name": "secret plans",
+            "type": 12,
+            "last_message_id": "155117677105512449",
+            "message_count": 4,
+            "member_count": 2,
+            "rate_limit_per_user": 0,
+            "thread_metadata": {
+                "archived": false,
+                "auto_archive_duration": 1440,
+                "archive_timestamp": "2021-12-28T18:02:47.183000+00:00",
+                "locked": false,
+                "invitable": false,
+                "create_timestamp": "2022-01-09T12:00:00.000000+00:00"
+            },
+            "member": {
+                "id": "41771983423143937",
+                "user_id": "41771983423143934",
+                "join_timestamp": "2022-01-09T12:00:00.000000+00:00",
+                "flags": 3
+            }
+        });
+
+        let channel: Channel = serde_json::from_value(json).unwrap();
+
+        assert_eq!(channel.kind(), Some(ChannelKind::GuildPrivateThread));
+        assert_eq!(channel.message_count(), Some(4));
+        assert_eq!(channel.member_count(), Some(2));
+
+        let metadata = channel.thread_metadata().unwrap();
+        assert!(!metadata.archived());
+        assert_eq!(metadata.archiver_id(), None);
+        assert_eq!(metadata.auto_archive_duration(), 1440);
+        assert_eq!(metadata.locked(), Some(false));
+        assert_eq!(metadata.invitable(), Some(false));
+        assert_eq!(
+            metadata.create_timestamp(),
+            Some(DateTime::parse_from_rfc3339("2022-01-09T12:00:00Z").unwrap())
+        );
+
+        let member = channel.member().unwrap();
+        assert_eq!(member.id(), channel.id());
+        assert_eq!(member.user_id(), 41771983423143934.into());
+        assert_eq!(
+            member.flags(),
+            ThreadMemberFlags::HAS_INTERACTED | ThreadMemberFlags::ALL_MESSAGES
+        );
+    }
+
+    fn message_json() -> serde_json::Value {
+        json!({
             "reactions": [
             {
                 "count": 1,
@@ -786,7 +1086,17 @@ mod tests {
             "channel_id": "290926798999357250",
             "mentions": [],
             "type": 0
-        });
+        })
+    }
+
+    #[test]
+    fn message_round_trip() {
+        assert_round_trip::<Message>(message_json());
+    }
+
+    #[test]
+    fn message_deserialize() {
+        let json = message_json();
 
         let msg: Message = serde_json::from_value(json).unwrap();
         let expected = Utc.ymd(2017, 7, 11).and_hms_milli(17, 27, 7, 299);
@@ -885,6 +1195,11 @@ mod tests {
         assert!(msg.mentions().is_empty());
         assert_eq!(msg.kind(), MessageKind::Default);
         assert_eq!(msg.flags(), Some(MessageFlags::IS_CROSSPOST));
+        assert!(msg.is_crosspost());
+        assert!(!msg.is_ephemeral());
+        assert!(!msg.is_reply());
+        assert!(!msg.has_thread());
+        assert!(!msg.is_pinned());
         // TODO: Check message reference
 
         let mention_channels = msg.mention_channels().unwrap();
@@ -904,4 +1219,491 @@ mod tests {
             "avatars/53908099506183680/a_bab14f271d565501444b2ca3be944b25"
         );
     }
+
+    fn reference_message_json(kind: u64) -> serde_json::Value {
+        json!({
+            "id": "334385199974967042",
+            "channel_id": "290926798999357250",
+            "author": {
+                "username": "Mason",
+                "discriminator": "9999",
+                "id": "53908099506183680",
+                "avatar": null
+            },
+            "content": "",
+            "timestamp": "2017-07-11T17:27:07.299000+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": kind
+        })
+    }
+
+    #[test]
+    fn message_deserialize_suppress_notifications() {
+        let mut json = reference_message_json(0);
+        json["flags"] = json!(4096);
+
+        let msg: Message = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            msg.try_flags(),
+            Some(Ok(MessageFlags::SUPPRESS_NOTIFICATIONS))
+        );
+    }
+
+    #[test]
+    fn message_deserialize_unknown_mention_and_interaction_kinds() {
+        let mut json = reference_message_json(20);
+        json["mention_channels"] = json!([
+            {
+                "id": "278325129692446722",
+                "guild_id": "278325129692446720",
+                "name": "big-news",
+                "type": 99
+            }
+        ]);
+        json["interaction"] = json!({
+            "id": "846462639134605312",
+            "type": 98,
+            "name": "ping",
+            "user": {
+                "id": "53908099506183680",
+                "username": "Mason",
+                "discriminator": "9999",
+                "avatar": null
+            }
+        });
+
+        let msg: Message = serde_json::from_value(json).unwrap();
+
+        let mention = &msg.mention_channels().unwrap()[0];
+        assert_eq!(mention.name(), "big-news");
+        assert_eq!(mention.try_kind().unwrap_err().inner(), 99);
+
+        let interaction = msg.interaction().unwrap();
+        assert_eq!(interaction.name(), "ping");
+        assert_eq!(interaction.try_kind().unwrap_err().inner(), 98);
+    }
+
+    #[test]
+    fn message_predicates() {
+        let msg: Message =
+            serde_json::from_value(reference_message_json(0)).unwrap();
+
+        assert!(!msg.is_reply());
+        assert!(!msg.is_crosspost());
+        assert!(!msg.is_ephemeral());
+        assert!(!msg.has_thread());
+
+        let mut json = reference_message_json(19);
+        json["pinned"] = json!(true);
+        json["flags"] = json!(
+            (MessageFlags::EPHEMERAL | MessageFlags::HAS_THREAD).bits()
+                | 1 << 40
+        );
+
+        let msg: Message = serde_json::from_value(json).unwrap();
+
+        assert!(msg.is_reply());
+        assert!(msg.is_pinned());
+        assert!(msg.is_ephemeral());
+        assert!(msg.has_thread());
+        assert!(!msg.is_crosspost());
+    }
+
+    #[test]
+    fn message_deserialize_sticker_items() {
+        let mut json = reference_message_json(0);
+        json["sticker_items"] = json!([
+            {
+                "id": "749054660769218631",
+                "name": "Wave",
+                "format_type": 3
+            },
+            {
+                "id": "749054660769218632",
+                "name": "Dance",
+                "format_type": 2
+            }
+        ]);
+
+        let msg: Message = serde_json::from_value(json).unwrap();
+
+        assert!(msg.stickers().is_none());
+
+        let items = msg.sticker_items().unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].id(), 749054660769218631.into());
+        assert_eq!(items[0].name(), "Wave");
+        assert_eq!(items[0].format_kind(), StickerFormat::Lottie);
+        assert_eq!(items[1].name(), "Dance");
+        assert_eq!(items[1].format_kind(), StickerFormat::APng);
+    }
+
+    #[test]
+    fn message_deserialize_reply() {
+        let mut json = reference_message_json(19);
+        json["content"] = json!("me too");
+        json["message_reference"] = json!({
+            "channel_id": "290926798999357250",
+            "message_id": "306588351130107906"
+        });
+
+        let msg: Message = serde_json::from_value(json).unwrap();
+
+        assert_eq!(msg.kind(), MessageKind::Reply);
+        assert!(msg.message_snapshots().is_none());
+
+        let reference = msg.message_reference().unwrap();
+        assert_eq!(reference.kind(), MessageReferenceKind::Default);
+        assert_eq!(reference.message_id(), Some(306588351130107906.into()));
+        assert_eq!(reference.channel_id(), Some(290926798999357250.into()));
+        assert_eq!(reference.guild_id(), None);
+    }
+
+    #[test]
+    fn message_deserialize_forward() {
+        let mut json = reference_message_json(0);
+        json["message_reference"] = json!({
+            "type": 1,
+            "channel_id": "278325129692446722",
+            "guild_id": "278325129692446720",
+            "message_id": "306588351130107906"
+        });
+        json["message_snapshots"] = json!([{
+            "message": {
+                "type": 0,
+                "content": "Big news!",
+                "embeds": [],
+                "attachments": [],
+                "timestamp": "2017-07-11T17:27:07.299000+00:00",
+                "edited_timestamp": null,
+                "flags": 0,
+                "mentions": [],
+                "mention_roles": ["41771983423143936"]
+            }
+        }]);
+
+        let msg: Message = serde_json::from_value(json).unwrap();
+
+        let reference = msg.message_reference().unwrap();
+        assert_eq!(reference.kind(), MessageReferenceKind::Forward);
+        assert_eq!(reference.guild_id(), Some(278325129692446720.into()));
+
+        let snapshots = msg.message_snapshots().unwrap();
+        assert_eq!(snapshots.len(), 1);
+
+        let snapshot = snapshots[0].message();
+        assert_eq!(snapshot.kind(), MessageKind::Default);
+        assert_eq!(snapshot.content(), "Big news!");
+        assert!(snapshot.embeds().is_empty());
+        assert!(snapshot.attachments().is_empty());
+        assert_eq!(snapshot.timestamp(), msg.timestamp());
+        assert_eq!(snapshot.edited_timestamp(), None);
+        assert_eq!(snapshot.flags(), Some(MessageFlags::empty()));
+        assert_eq!(snapshot.mention_roles(), [41771983423143936.into()]);
+    }
+
+    #[test]
+    fn message_deserialize_forward_with_mention() {
+        let mut json = reference_message_json(0);
+        json["message_snapshots"] = json!([{
+            "message": {
+                "type": 0,
+                "content": "hi <@53908099506183680>",
+                "timestamp": "2017-07-11T17:27:07.299000+00:00",
+                "edited_timestamp": null,
+                "flags": 0,
+                "mentions": [{
+                    "id": "53908099506183680",
+                    "username": "Mason",
+                    "discriminator": "9999",
+                    "avatar": null
+                }]
+            }
+        }]);
+
+        let msg: Message = serde_json::from_value(json).unwrap();
+
+        let snapshot = msg.message_snapshots().unwrap()[0].message();
+        assert_eq!(snapshot.mentions().len(), 1);
+        assert_eq!(snapshot.mentions()[0].id(), 53908099506183680.into());
+        assert!(snapshot.embeds().is_empty());
+        assert!(snapshot.mention_roles().is_empty());
+    }
+
+    #[test]
+    fn attachment_deserialize_description() {
+        let json = json!({
+            "id": "1021496225361580032",
+            "filename": "cat.png",
+            "description": "A cat sitting in a box",
+            "content_type": "image/png",
+            "size": 8675309,
+            "url": "https://cdn.discordapp.com/attachments/1/2/cat.png",
+            "proxy_url": "https://media.discordapp.net/attachments/1/2/cat.png",
+            "height": 480,
+            "width": 640,
+            "ephemeral": true
+        });
+
+        let attachment: Attachment = serde_json::from_value(json).unwrap();
+
+        assert_eq!(attachment.id(), 1021496225361580032.into());
+        assert_eq!(attachment.filename(), "cat.png");
+        assert_eq!(attachment.description(), Some("A cat sitting in a box"));
+        assert_eq!(attachment.content_type(), Some("image/png"));
+        assert_eq!(attachment.size(), 8675309);
+        assert_eq!(attachment.height(), Some(480));
+        assert_eq!(attachment.width(), Some(640));
+        assert_eq!(attachment.ephemeral(), Some(true));
+    }
+
+    #[test]
+    fn followed_channel_deserialize() {
+        let json = json!({
+            "channel_id": "41771983423143937",
+            "webhook_id": "41771983423143938"
+        });
+
+        let followed: FollowedChannel = serde_json::from_value(json).unwrap();
+
+        assert_eq!(followed.channel_id(), 41771983423143937.into());
+        assert_eq!(followed.webhook_id(), 41771983423143938.into());
+    }
+
+    #[test]
+    fn new_channel_follower_serialize() {
+        let follower = NewChannelFollower {
+            webhook_channel_id: 41771983423143937.into(),
+        };
+
+        let json = serde_json::to_value(&follower).unwrap();
+
+        assert_eq!(json, json!({"webhook_channel_id": "41771983423143937"}));
+    }
+
+    #[test]
+    fn sticker_pack_deserialize_standard() {
+        let json = json!({
+            "id": "847199849233514549",
+            "stickers": [
+                {
+                    "id": "749054660769218631",
+                    "pack_id": "847199849233514549",
+                    "name": "Wave",
+                    "description": "Wumpus waves hello",
+                    "tags": "wumpus, hello, sup, hi, oi, heyo, heya, yo, wave",
+                    "type": 1,
+                    "format_type": 3,
+                    "sort_value": 12
+                }
+            ],
+            "name": "Wumpus Beyond",
+            "sku_id": "847199849233514547",
+            "cover_sticker_id": "749054660769218631",
+            "description": "Say hello to Wumpus!",
+            "banner_asset_id": "761773777976819732"
+        });
+
+        let pack: StickerPack = serde_json::from_value(json).unwrap();
+
+        assert_eq!(pack.id(), 847199849233514549.into());
+        assert_eq!(pack.name(), "Wumpus Beyond");
+        assert_eq!(pack.sku_id(), 847199849233514547.into());
+        assert_eq!(pack.cover_sticker_id(), Some(749054660769218631.into()));
+        assert_eq!(pack.description(), "Say hello to Wumpus!");
+        assert_eq!(pack.banner_asset_id(), Some(761773777976819732.into()));
+
+        let stickers = pack.stickers();
+        assert_eq!(stickers.len(), 1);
+
+        let sticker = &stickers[0];
+        assert_eq!(sticker.id(), 749054660769218631.into());
+        assert_eq!(sticker.pack_id(), Some(847199849233514549.into()));
+        assert_eq!(sticker.name(), "Wave");
+        assert_eq!(sticker.description(), Some("Wumpus waves hello"));
+        assert_eq!(sticker.kind(), Some(StickerKind::Standard));
+        assert_eq!(sticker.format_kind(), StickerFormat::Lottie);
+        assert_eq!(sticker.sort_value(), Some(12));
+        assert_eq!(sticker.guild_id(), None);
+        assert!(sticker.user().is_none());
+    }
+
+    #[test]
+    fn sticker_deserialize_guild() {
+        let json = json!({
+            "id": "816068219226013716",
+            "name": "party",
+            "description": null,
+            "tags": "tada",
+            "type": 2,
+            "format_type": 1,
+            "available": true,
+            "guild_id": "41771983429143937",
+            "user": {
+                "id": "53908099506183680",
+                "username": "Mason",
+                "discriminator": "9999",
+                "avatar": null
+            }
+        });
+
+        let sticker: Sticker = serde_json::from_value(json).unwrap();
+
+        assert_eq!(sticker.id(), 816068219226013716.into());
+        assert_eq!(sticker.pack_id(), None);
+        assert_eq!(sticker.name(), "party");
+        assert_eq!(sticker.description(), None);
+        assert_eq!(sticker.tags(), Some("tada"));
+        assert_eq!(sticker.kind(), Some(StickerKind::Guild));
+        assert_eq!(sticker.format_kind(), StickerFormat::Png);
+        assert_eq!(sticker.available(), Some(true));
+        assert_eq!(sticker.guild_id(), Some(41771983429143937.into()));
+        assert_eq!(sticker.user().unwrap().username(), "Mason");
+    }
+
+    #[test]
+    fn channel_deserialize_unknown_enums() {
+        let json = json!({
+            "id": "41771983423143937",
+            "type": 99,
+            "video_quality_mode": 42
+        });
+
+        let channel: Channel = serde_json::from_value(json).unwrap();
+
+        assert_eq!(channel.try_kind().unwrap().unwrap_err().inner(), 99);
+        assert_eq!(
+            channel
+                .try_video_quality_mode()
+                .unwrap()
+                .unwrap_err()
+                .inner(),
+            42
+        );
+    }
+
+    #[test]
+    fn nonce_snowflake_unique() {
+        let a = Nonce::snowflake();
+        let b = Nonce::snowflake();
+
+        assert_ne!(a, b);
+
+        for nonce in [a, b].iter() {
+            let json = serde_json::to_value(nonce).unwrap();
+            let txt = json.as_str().unwrap();
+            assert!(txt.parse::<u64>().is_ok());
+        }
+    }
+
+    #[test]
+    fn overwrite_serialize_new() {
+        let overwrite = Overwrite::new(
+            OverwriteId::Member(41771983423143937.into()),
+            Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL,
+            Permissions::MANAGE_MESSAGES,
+        );
+
+        let json = serde_json::to_value(&overwrite).unwrap();
+
+        assert_eq!(
+            json,
+            json!({
+                "id": "41771983423143937",
+                "type": 1,
+                "allow": "3072",
+                "deny": "8192"
+            })
+        );
+    }
+
+    #[test]
+    fn overwrite_deserialize_unknown_kind() {
+        let json = json!({
+            "id": "41771983423143937",
+            "guild_id": "41771983429143937",
+            "name": "general",
+            "type": 0,
+            "position": 0,
+            "permission_overwrites": [
+                {
+                    "id": "41771983423143937",
+                    "type": 7,
+                    "allow": "0",
+                    "deny": "1024"
+                }
+            ]
+        });
+
+        let channel: Channel = serde_json::from_value(json).unwrap();
+        let overwrites = channel.permission_overwrites().unwrap();
+
+        assert_eq!(
+            overwrites[0].id(),
+            OverwriteId::Other {
+                id: 41771983423143937.into(),
+                kind: 7,
+            }
+        );
+        assert!(!overwrites[0].id().is_member());
+        assert!(!overwrites[0].id().is_role());
+        assert_eq!(overwrites[0].deny(), Permissions::VIEW_CHANNEL);
+
+        let json = serde_json::to_value(&overwrites[0]).unwrap();
+        assert_eq!(json["type"], 7);
+    }
+
+    #[test]
+    fn reaction_deserialize_unicode() {
+        let json = json!({
+            "count": 3,
+            "me": true,
+            "emoji": {
+                "id": null,
+                "name": "🔥"
+            }
+        });
+
+        let reaction: Reaction = serde_json::from_value(json).unwrap();
+
+        assert_eq!(reaction.count(), 3);
+        assert!(reaction.me());
+        assert_eq!(reaction.emoji().id(), None);
+        assert_eq!(reaction.emoji().name(), Some("🔥"));
+        assert_eq!(reaction.emoji().animated(), None);
+        assert_eq!(reaction.reaction_form().as_deref(), Some("🔥"));
+    }
+
+    #[test]
+    fn reaction_deserialize_custom() {
+        let json = json!({
+            "count": 1,
+            "me": false,
+            "emoji": {
+                "id": "41771983429993937",
+                "name": "LUL",
+                "animated": true
+            }
+        });
+
+        let reaction: Reaction = serde_json::from_value(json).unwrap();
+
+        assert_eq!(reaction.count(), 1);
+        assert!(!reaction.me());
+        assert_eq!(reaction.emoji().id(), Some(41771983429993937.into()));
+        assert_eq!(reaction.emoji().name(), Some("LUL"));
+        assert_eq!(reaction.emoji().animated(), Some(true));
+        assert_eq!(
+            reaction.reaction_form().as_deref(),
+            Some("LUL:41771983429993937")
+        );
+    }
 }
@@ -0,0 +1,166 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::enums::{EnumFromIntegerError, IntegerEnum};
+
+use serde::{Deserialize, Serialize};
+
+use std::convert::TryFrom;
+
+use typed_builder::TypedBuilder;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ComponentKind {
+    ActionRow,
+    Button,
+    SelectMenu,
+}
+
+impl TryFrom<u64> for ComponentKind {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            1 => Self::ActionRow,
+            2 => Self::Button,
+            3 => Self::SelectMenu,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<ComponentKind> for u64 {
+    fn from(u: ComponentKind) -> Self {
+        match u {
+            ComponentKind::ActionRow => 1,
+            ComponentKind::Button => 2,
+            ComponentKind::SelectMenu => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ButtonStyle {
+    Primary,
+    Secondary,
+    Success,
+    Danger,
+    /// Navigates to `url` instead of sending an interaction.
+    Link,
+}
+
+impl TryFrom<u64> for ButtonStyle {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            1 => Self::Primary,
+            2 => Self::Secondary,
+            3 => Self::Success,
+            4 => Self::Danger,
+            5 => Self::Link,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<ButtonStyle> for u64 {
+    fn from(u: ButtonStyle) -> Self {
+        match u {
+            ButtonStyle::Primary => 1,
+            ButtonStyle::Secondary => 2,
+            ButtonStyle::Success => 3,
+            ButtonStyle::Danger => 4,
+            ButtonStyle::Link => 5,
+        }
+    }
+}
+
+/// An interactive element attached to a message.
+///
+/// Top-level components must be action rows, which hold the other kinds.
+#[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
+pub struct Component {
+    #[builder(setter(into))]
+    #[serde(rename = "type")]
+    kind: IntegerEnum<ComponentKind>,
+
+    #[builder(default, setter(into, strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_id: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    disabled: Option<bool>,
+
+    #[builder(default, setter(into, strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<IntegerEnum<ButtonStyle>>,
+
+    #[builder(default, setter(into, strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+
+    #[builder(default, setter(into, strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+
+    #[builder(default, setter(into, strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<Component>>,
+}
+
+impl Component {
+    pub fn action_row<C>(components: C) -> Self
+    where
+        C: Into<Vec<Component>>,
+    {
+        Self::builder()
+            .kind(ComponentKind::ActionRow)
+            .components(components)
+            .build()
+    }
+
+    pub fn try_kind(&self) -> Result<ComponentKind, EnumFromIntegerError> {
+        self.kind.try_unwrap()
+    }
+
+    pub fn kind(&self) -> ComponentKind {
+        self.kind.unwrap()
+    }
+
+    pub fn custom_id(&self) -> Option<&str> {
+        self.custom_id.as_deref()
+    }
+
+    pub fn disabled(&self) -> Option<bool> {
+        self.disabled
+    }
+
+    pub fn try_style(
+        &self,
+    ) -> Option<Result<ButtonStyle, EnumFromIntegerError>> {
+        self.style.map(IntegerEnum::try_unwrap)
+    }
+
+    pub fn style(&self) -> Option<ButtonStyle> {
+        self.style.map(IntegerEnum::unwrap)
+    }
+
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    pub fn components(&self) -> Option<&[Component]> {
+        self.components.as_deref()
+    }
+}
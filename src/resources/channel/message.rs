@@ -15,15 +15,17 @@ use crate::resources::emoji::Emoji;
 use crate::resources::guild::{GuildId, GuildMember};
 use crate::resources::user::{User, UserId};
 use crate::resources::webhook::WebhookId;
-use crate::snowflake::Id;
+use crate::snowflake::{AnyId, Id, EPOCH};
 
 use serde::{Deserialize, Serialize};
 
 use std::convert::TryFrom;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use super::embed::*;
-use super::{Channel, ChannelId, ChannelKind};
+use super::{Channel, ChannelId, ChannelKind, Component};
 
 use typed_builder::TypedBuilder;
 
@@ -33,12 +35,14 @@ pub type AttachmentId = Id<Attachment>;
 pub struct Attachment {
     id: AttachmentId,
     filename: String,
+    description: Option<String>,
     content_type: Option<String>,
     size: u64,
     url: String,
     proxy_url: String,
     height: Option<u64>,
     width: Option<u64>,
+    ephemeral: Option<bool>,
 }
 
 impl Attachment {
@@ -50,6 +54,11 @@ impl Attachment {
         &self.filename
     }
 
+    /// Alt text for the attachment.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
     pub fn content_type(&self) -> Option<&str> {
         self.content_type.as_deref()
     }
@@ -69,6 +78,47 @@ impl Attachment {
     pub fn height(&self) -> Option<u64> {
         self.height
     }
+
+    pub fn width(&self) -> Option<u64> {
+        self.width
+    }
+
+    /// Whether the attachment will be removed after a set period of time,
+    /// as with attachments on ephemeral interaction responses.
+    pub fn ephemeral(&self) -> Option<bool> {
+        self.ephemeral
+    }
+}
+
+/// An attachment already on a message, to keep when editing it.
+///
+/// Editing a message with a list of existing attachments removes any
+/// attachment whose id isn't listed.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ExistingAttachment {
+    id: AttachmentId,
+}
+
+impl ExistingAttachment {
+    pub fn new(id: AttachmentId) -> Self {
+        Self { id }
+    }
+
+    pub fn id(&self) -> AttachmentId {
+        self.id
+    }
+}
+
+impl From<AttachmentId> for ExistingAttachment {
+    fn from(id: AttachmentId) -> Self {
+        Self::new(id)
+    }
+}
+
+impl From<&Attachment> for ExistingAttachment {
+    fn from(attachment: &Attachment) -> Self {
+        Self::new(attachment.id)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -88,13 +138,56 @@ impl Mention {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Nonce {
     Integer(u64),
     String(String),
 }
 
+impl Nonce {
+    /// Generate a fresh nonce shaped like a snowflake for the current time.
+    ///
+    /// The low bits hold a process-wide counter, so nonces generated in the
+    /// same millisecond still differ. The nonce is sent as a string.
+    pub fn snowflake() -> Self {
+        static INCREMENT: AtomicU64 = AtomicU64::new(0);
+
+        let unix_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+
+        let discord_ms = unix_ms.saturating_sub(EPOCH) & 0x3FF_FFFF_FFFF;
+        let increment = INCREMENT.fetch_add(1, Ordering::Relaxed) & 0x3F_FFFF;
+
+        Self::String(((discord_ms << 22) | increment).to_string())
+    }
+
+    /// Use an externally generated unique string, like a UUID, as a nonce.
+    ///
+    /// Discord rejects nonces longer than 25 characters, so a hyphenated UUID
+    /// must be shortened (for example, to its base64 form) first.
+    pub fn string<S>(s: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::String(s.into())
+    }
+}
+
+impl From<u64> for Nonce {
+    fn from(u: u64) -> Self {
+        Self::Integer(u)
+    }
+}
+
+impl From<String> for Nonce {
+    fn from(s: String) -> Self {
+        Self::String(s)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum MessageKind {
     Default,
@@ -226,6 +319,9 @@ bitflags! {
         const HAS_THREAD = 1<<5;
         const EPHEMERAL = 1<<6;
         const LOADING = 1<<7;
+        const FAILED_TO_MENTION_SOME_ROLES_IN_THREAD = 1<<8;
+        const SUPPRESS_NOTIFICATIONS = 1<<12;
+        const IS_VOICE_MESSAGE = 1<<13;
     }
 }
 
@@ -274,9 +370,11 @@ pub struct Message {
     message_reference: Option<MessageReference>,
     flags: Option<IntegerEnum<MessageFlags>>,
     stickers: Option<Vec<Sticker>>,
+    sticker_items: Option<Vec<StickerItem>>,
     referenced_message: Option<Box<Message>>,
     interaction: Option<MessageInteraction>,
     thread: Option<Channel>,
+    message_snapshots: Option<Vec<MessageSnapshot>>,
 }
 
 impl Message {
@@ -390,10 +488,16 @@ impl Message {
         self.flags.map(IntegerEnum::unwrap)
     }
 
+    /// Deprecated by Discord in favour of
+    /// [`sticker_items`](Message::sticker_items).
     pub fn stickers(&self) -> Option<&[Sticker]> {
         self.stickers.as_deref()
     }
 
+    pub fn sticker_items(&self) -> Option<&[StickerItem]> {
+        self.sticker_items.as_deref()
+    }
+
     pub fn referenced_message(&self) -> Option<&Message> {
         self.referenced_message.as_deref()
     }
@@ -405,6 +509,119 @@ impl Message {
     pub fn thread(&self) -> Option<&Channel> {
         self.thread.as_ref()
     }
+
+    /// Copies of the forwarded message, when this message is a forward.
+    pub fn message_snapshots(&self) -> Option<&[MessageSnapshot]> {
+        self.message_snapshots.as_deref()
+    }
+
+    /// Whether `flag` is set, checking the raw bits so that unknown flags
+    /// elsewhere don't hide it.
+    fn has_flag(&self, flag: MessageFlags) -> bool {
+        matches!(self.flags, Some(f) if u64::from(f) & flag.bits() == flag.bits())
+    }
+
+    pub fn is_reply(&self) -> bool {
+        matches!(self.try_kind(), Ok(MessageKind::Reply))
+    }
+
+    /// Whether this message was crossposted into this channel from a
+    /// followed news channel.
+    pub fn is_crosspost(&self) -> bool {
+        self.has_flag(MessageFlags::IS_CROSSPOST)
+    }
+
+    pub fn is_ephemeral(&self) -> bool {
+        self.has_flag(MessageFlags::EPHEMERAL)
+    }
+
+    pub fn has_thread(&self) -> bool {
+        self.thread.is_some() || self.has_flag(MessageFlags::HAS_THREAD)
+    }
+
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+}
+
+/// A copy of a forwarded message, taken when it was forwarded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageSnapshot {
+    message: SnapshotMessage,
+}
+
+impl MessageSnapshot {
+    pub fn message(&self) -> &SnapshotMessage {
+        &self.message
+    }
+}
+
+/// The subset of [`Message`] fields included in a [`MessageSnapshot`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotMessage {
+    #[serde(rename = "type")]
+    kind: IntegerEnum<MessageKind>,
+    content: String,
+    #[serde(default)]
+    embeds: Vec<Embed>,
+    #[serde(default)]
+    attachments: Vec<Attachment>,
+    timestamp: DateTime<FixedOffset>,
+    edited_timestamp: Option<DateTime<FixedOffset>>,
+    flags: Option<IntegerEnum<MessageFlags>>,
+    #[serde(default)]
+    mentions: Vec<User>,
+    #[serde(default)]
+    mention_roles: Vec<RoleId>,
+}
+
+impl SnapshotMessage {
+    pub fn try_kind(&self) -> Result<MessageKind, EnumFromIntegerError> {
+        self.kind.try_unwrap()
+    }
+
+    pub fn kind(&self) -> MessageKind {
+        self.kind.unwrap()
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn embeds(&self) -> &[Embed] {
+        &self.embeds
+    }
+
+    pub fn attachments(&self) -> &[Attachment] {
+        &self.attachments
+    }
+
+    pub fn timestamp(&self) -> DateTime<FixedOffset> {
+        self.timestamp
+    }
+
+    pub fn edited_timestamp(&self) -> Option<DateTime<FixedOffset>> {
+        self.edited_timestamp
+    }
+
+    pub fn try_flags(
+        &self,
+    ) -> Option<Result<MessageFlags, EnumFromIntegerError>> {
+        self.flags.map(IntegerEnum::try_unwrap)
+    }
+
+    pub fn flags(&self) -> Option<MessageFlags> {
+        self.flags.map(IntegerEnum::unwrap)
+    }
+
+    /// Users mentioned in the forwarded message.
+    pub fn mentions(&self) -> &[User] {
+        &self.mentions
+    }
+
+    pub fn mention_roles(&self) -> &[RoleId] {
+        &self.mention_roles
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Copy)]
@@ -465,15 +682,72 @@ impl MessageActivity {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MessageReferenceKind {
+    Default,
+    Forward,
+}
+
+impl TryFrom<u64> for MessageReferenceKind {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            0 => Self::Default,
+            1 => Self::Forward,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<MessageReferenceKind> for u64 {
+    fn from(u: MessageReferenceKind) -> Self {
+        match u {
+            MessageReferenceKind::Default => 0,
+            MessageReferenceKind::Forward => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 pub struct MessageReference {
+    #[builder(default, setter(strip_option, into))]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    kind: Option<IntegerEnum<MessageReferenceKind>>,
+
+    #[builder(default, setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     message_id: Option<MessageId>,
+
+    #[builder(default, setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     channel_id: Option<ChannelId>,
+
+    #[builder(default, setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     guild_id: Option<GuildId>,
+
+    #[builder(default, setter(strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     fail_if_not_exist: Option<bool>,
 }
 
 impl MessageReference {
+    /// Whether this is a reply or a forward. A missing `type` means a reply.
+    pub fn try_kind(
+        &self,
+    ) -> Result<MessageReferenceKind, EnumFromIntegerError> {
+        self.kind
+            .map(IntegerEnum::try_unwrap)
+            .unwrap_or(Ok(MessageReferenceKind::Default))
+    }
+
+    pub fn kind(&self) -> MessageReferenceKind {
+        self.try_kind().unwrap()
+    }
+
     pub fn message_id(&self) -> Option<MessageId> {
         self.message_id
     }
@@ -556,21 +830,155 @@ impl MessageInteraction {
 pub type StickerId = Id<Sticker>;
 pub type StickerPackId = Id<StickerPack>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StickerPack {
-    _p: (),
+    id: StickerPackId,
+    stickers: Vec<Sticker>,
+    name: String,
+    sku_id: AnyId,
+    cover_sticker_id: Option<StickerId>,
+    description: String,
+    banner_asset_id: Option<AnyId>,
+}
+
+impl StickerPack {
+    pub fn id(&self) -> StickerPackId {
+        self.id
+    }
+
+    pub fn stickers(&self) -> &[Sticker] {
+        &self.stickers
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn sku_id(&self) -> AnyId {
+        self.sku_id
+    }
+
+    pub fn cover_sticker_id(&self) -> Option<StickerId> {
+        self.cover_sticker_id
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn banner_asset_id(&self) -> Option<AnyId> {
+        self.banner_asset_id
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct StickerPacks {
+    pub(crate) sticker_packs: Vec<StickerPack>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct NewMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) content: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) nonce: Option<Nonce>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) enforce_nonce: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tts: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) embeds: Option<Vec<Embed>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) allowed_mentions: Option<AllowedMentions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) message_reference: Option<MessageReference>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) sticker_ids: Option<Vec<StickerId>>,
+}
+
+/// Changes to a message. Fields set to `Some(None)` are cleared.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct EditMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) content: Option<Option<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) embeds: Option<Option<Vec<Embed>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) allowed_mentions: Option<Option<AllowedMentions>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) components: Option<Option<Vec<Component>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) attachments: Option<Vec<ExistingAttachment>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct EditSticker {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tags: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum StickerKind {
+    Standard,
+    Guild,
+}
+
+impl TryFrom<u64> for StickerKind {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            1 => Self::Standard,
+            2 => Self::Guild,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<StickerKind> for u64 {
+    fn from(u: StickerKind) -> Self {
+        match u {
+            StickerKind::Standard => 1,
+            StickerKind::Guild => 2,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Sticker {
     id: StickerId,
-    pack_id: StickerPackId,
+    pack_id: Option<StickerPackId>,
     name: String,
-    description: String,
+    description: Option<String>,
     tags: Option<String>,
-    asset: String,
+    asset: Option<String>,
+    #[serde(rename = "type")]
+    kind: Option<IntegerEnum<StickerKind>>,
     #[serde(rename = "format_type")]
     format_kind: IntegerEnum<StickerFormat>,
+    available: Option<bool>,
+    guild_id: Option<GuildId>,
+    user: Option<User>,
+    sort_value: Option<u64>,
 }
 
 impl Sticker {
@@ -578,7 +986,7 @@ impl Sticker {
         self.id
     }
 
-    pub fn pack_id(&self) -> StickerPackId {
+    pub fn pack_id(&self) -> Option<StickerPackId> {
         self.pack_id
     }
 
@@ -586,16 +994,71 @@ impl Sticker {
         &self.name
     }
 
-    pub fn description(&self) -> &str {
-        &self.description
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
     }
 
     pub fn tags(&self) -> Option<&str> {
         self.tags.as_deref()
     }
 
-    pub fn asset(&self) -> &str {
-        &self.asset
+    pub fn asset(&self) -> Option<&str> {
+        self.asset.as_deref()
+    }
+
+    pub fn try_kind(
+        &self,
+    ) -> Option<Result<StickerKind, EnumFromIntegerError>> {
+        self.kind.map(IntegerEnum::try_unwrap)
+    }
+
+    pub fn kind(&self) -> Option<StickerKind> {
+        self.kind.map(IntegerEnum::unwrap)
+    }
+
+    pub fn available(&self) -> Option<bool> {
+        self.available
+    }
+
+    pub fn guild_id(&self) -> Option<GuildId> {
+        self.guild_id
+    }
+
+    pub fn user(&self) -> Option<&User> {
+        self.user.as_ref()
+    }
+
+    pub fn sort_value(&self) -> Option<u64> {
+        self.sort_value
+    }
+
+    pub fn try_format_kind(
+        &self,
+    ) -> Result<StickerFormat, EnumFromIntegerError> {
+        self.format_kind.try_unwrap()
+    }
+
+    pub fn format_kind(&self) -> StickerFormat {
+        self.format_kind.unwrap()
+    }
+}
+
+/// The smallest amount of data required to render a sticker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StickerItem {
+    id: StickerId,
+    name: String,
+    #[serde(rename = "format_type")]
+    format_kind: IntegerEnum<StickerFormat>,
+}
+
+impl StickerItem {
+    pub fn id(&self) -> StickerId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
     }
 
     pub fn try_format_kind(
@@ -660,6 +1123,11 @@ impl Reaction {
     pub fn emoji(&self) -> &Emoji {
         &self.emoji
     }
+
+    /// See [`Emoji::reaction_form`].
+    pub fn reaction_form(&self) -> Option<String> {
+        self.emoji.reaction_form()
+    }
 }
 
 #[derive(Debug, Clone, Eq, Copy, PartialEq, Hash)]
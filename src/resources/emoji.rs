@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use crate::image;
+use crate::image::{self, UploadImage};
 use crate::permissions::RoleId;
 use crate::resources::user::User;
 use crate::snowflake::Id;
@@ -88,6 +88,32 @@ impl Emoji {
     pub fn available(&self) -> Option<bool> {
         self.available
     }
+
+    /// The emoji as it appears in reaction endpoint paths: `name:id` for
+    /// custom emoji, or the unicode character(s) for standard emoji.
+    pub fn reaction_form(&self) -> Option<String> {
+        match (self.id, self.name.as_deref()) {
+            (Some(id), name) => Some(format!("{}:{}", name.unwrap_or("_"), id)),
+            (None, Some(name)) => Some(name.to_owned()),
+            (None, None) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct NewEmoji {
+    pub(crate) name: String,
+    pub(crate) image: UploadImage,
+    pub(crate) roles: Vec<RoleId>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct EditEmoji {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) roles: Option<Option<Vec<RoleId>>>,
 }
 
 #[cfg(test)]
@@ -114,7 +140,8 @@ mod tests {
             },
             "require_colons": true,
             "managed": false,
-            "animated": false
+            "animated": false,
+            "available": true
         });
 
         let emoji: Emoji = serde_json::from_value(json).unwrap();
@@ -129,6 +156,12 @@ mod tests {
         assert_eq!(emoji.require_colons(), Some(true));
         assert_eq!(emoji.managed(), Some(false));
         assert_eq!(emoji.animated(), Some(false));
+        assert_eq!(emoji.available(), Some(true));
+        assert_eq!(
+            emoji.image().unwrap().bare_path(),
+            "emojis/41771983429993937"
+        );
+        assert_eq!(emoji.reaction_form().unwrap(), "LUL:41771983429993937");
 
         let user = emoji.user().unwrap();
         assert_eq!(user.username(), "Luigi");
@@ -158,6 +191,14 @@ mod tests {
 
         assert_eq!(emoji.id(), None);
         assert_eq!(emoji.name(), Some("\u{1F525}"));
+        assert!(emoji.image().is_none());
+        assert!(emoji.roles().is_none());
+        assert!(emoji.user().is_none());
+        assert_eq!(emoji.require_colons(), None);
+        assert_eq!(emoji.managed(), None);
+        assert_eq!(emoji.animated(), None);
+        assert_eq!(emoji.available(), None);
+        assert_eq!(emoji.reaction_form().unwrap(), "\u{1F525}");
     }
 
     #[test]
@@ -13,21 +13,29 @@ use crate::enums::{
 };
 use crate::gateway::PresenceUpdateEvent;
 use crate::image;
+use crate::locale::Locale;
 use crate::permissions::{Permissions, Role, RoleId};
 use crate::resources::application::ApplicationId;
-use crate::resources::channel::{Channel, ChannelId};
+use crate::resources::channel::{Channel, ChannelId, Sticker};
 use crate::resources::emoji::{Emoji, EmojiId};
 use crate::resources::user::{User, UserId};
 use crate::resources::voice::VoiceState;
 use crate::snowflake::Id;
+use crate::str::obscure;
+
+use educe::Educe;
 
 pub use self::integration::*;
 
 use serde::{Deserialize, Serialize};
 
+use snafu::Snafu;
+
 use std::convert::TryFrom;
 use std::str::FromStr;
 
+use typed_builder::TypedBuilder;
+
 pub type GuildId = Id<Guild>;
 
 #[derive(Debug, Clone)]
@@ -112,6 +120,40 @@ impl image::Image for GuildDiscoverySplash {
     }
 }
 
+/// A guild-specific avatar for a member, which overrides their user avatar.
+#[derive(Debug, Clone)]
+pub struct GuildMemberAvatar {
+    has_gif: bool,
+    bare_path: String,
+}
+
+impl GuildMemberAvatar {
+    fn new(guild_id: GuildId, user_id: UserId, hash: &str) -> Self {
+        Self {
+            has_gif: hash.starts_with("a_"),
+            bare_path: format!(
+                "guilds/{}/users/{}/avatars/{}",
+                guild_id, user_id, hash
+            ),
+        }
+    }
+}
+
+impl image::Image for GuildMemberAvatar {
+    fn supports(&self, format: image::Format) -> bool {
+        match format {
+            image::Format::Jpeg | image::Format::Png | image::Format::WebP => {
+                true
+            }
+            image::Format::Gif => self.has_gif,
+        }
+    }
+
+    fn bare_path(&self) -> &str {
+        &self.bare_path
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GuildBanner {
     bare_path: String,
@@ -237,6 +279,33 @@ impl From<ExplicitContentFilterLevel> for u64 {
     }
 }
 
+/// The look of a guild's widget image.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum WidgetStyle {
+    /// A small shield showing the online member count.
+    Shield,
+    /// A large image with the guild icon, name, and online count.
+    Banner1,
+    /// A smaller version of `Banner1`.
+    Banner2,
+    /// A large image with a "Chat Now" button.
+    Banner3,
+    /// A large image with a "Join My Server" button.
+    Banner4,
+}
+
+impl AsRef<str> for WidgetStyle {
+    fn as_ref(&self) -> &str {
+        match self {
+            WidgetStyle::Shield => "shield",
+            WidgetStyle::Banner1 => "banner1",
+            WidgetStyle::Banner2 => "banner2",
+            WidgetStyle::Banner3 => "banner3",
+            WidgetStyle::Banner4 => "banner4",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum GuildFeature {
     AnimatedIcon,
@@ -380,6 +449,41 @@ impl From<PremiumTier> for u64 {
     }
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum NsfwLevel {
+    Default,
+    Explicit,
+    Safe,
+    AgeRestricted,
+}
+
+impl TryFrom<u64> for NsfwLevel {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            0 => Self::Default,
+            1 => Self::Explicit,
+            2 => Self::Safe,
+            3 => Self::AgeRestricted,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<NsfwLevel> for u64 {
+    fn from(u: NsfwLevel) -> Self {
+        match u {
+            NsfwLevel::Default => 0,
+            NsfwLevel::Explicit => 1,
+            NsfwLevel::Safe => 2,
+            NsfwLevel::AgeRestricted => 3,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WelcomeScreen {
     description: Option<String>,
@@ -396,11 +500,17 @@ impl WelcomeScreen {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 pub struct WelcomeScreenChannel {
     channel_id: ChannelId,
+
+    #[builder(setter(into))]
     description: String,
+
+    #[builder(default, setter(strip_option))]
     emoji_id: Option<EmojiId>,
+
+    #[builder(default, setter(strip_option, into))]
     emoji_name: Option<String>,
 }
 
@@ -422,6 +532,18 @@ impl WelcomeScreenChannel {
     }
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct EditWelcomeScreen {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) enabled: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) welcome_channels: Option<Vec<WelcomeScreenChannel>>,
+}
+
 mod unavailable {
     use serde::de::{Deserialize, Deserializer, Error as _, Unexpected};
     use serde::ser::Serializer;
@@ -479,6 +601,20 @@ pub enum Guild {
     Unavailable(UnavailableGuild),
 }
 
+/// Returned by [`Guild::require_available`] when the guild is unavailable,
+/// usually because of an outage.
+#[derive(Debug, Snafu, Eq, PartialEq, Clone)]
+#[snafu(display("guild {} is unavailable", id))]
+pub struct GuildUnavailableError {
+    id: GuildId,
+}
+
+impl GuildUnavailableError {
+    pub fn id(&self) -> GuildId {
+        self.id
+    }
+}
+
 impl Guild {
     pub fn id(&self) -> GuildId {
         match self {
@@ -494,6 +630,19 @@ impl Guild {
         }
     }
 
+    /// The guild's name, if it is available.
+    pub fn name(&self) -> Option<&str> {
+        self.as_available().map(AvailableGuild::name)
+    }
+
+    /// Like [`Guild::as_available`], but as a `Result` so it works with `?`.
+    pub fn require_available(
+        &self,
+    ) -> Result<&AvailableGuild, GuildUnavailableError> {
+        self.as_available()
+            .ok_or_else(|| GuildUnavailableError { id: self.id() })
+    }
+
     pub fn into_available(self) -> Option<AvailableGuild> {
         match self {
             Self::Available(a) => Some(a),
@@ -607,12 +756,17 @@ pub struct AvailableGuild {
     banner: Option<String>,
     premium_tier: IntegerEnum<PremiumTier>,
     premium_subscription_count: Option<u64>,
-    preferred_locale: String,
+    preferred_locale: Locale,
     public_updates_channel_id: Option<ChannelId>,
     max_video_channel_users: Option<u64>,
+    max_stage_video_channel_users: Option<u64>,
     approximate_member_count: Option<u64>,
+    approximate_presence_count: Option<u64>,
     welcome_screen: Option<WelcomeScreen>,
     nsfw: Option<bool>,
+    nsfw_level: Option<IntegerEnum<NsfwLevel>>,
+    stickers: Option<Vec<Sticker>>,
+    premium_progress_bar_enabled: Option<bool>,
 }
 
 impl AvailableGuild {
@@ -782,6 +936,17 @@ impl AvailableGuild {
         self.voice_states.as_deref()
     }
 
+    /// The voice states of users connected to `channel_id`.
+    pub fn voice_states_in(
+        &self,
+        channel_id: ChannelId,
+    ) -> impl Iterator<Item = &VoiceState> {
+        self.voice_states
+            .iter()
+            .flatten()
+            .filter(move |v| v.channel_id() == Some(channel_id))
+    }
+
     pub fn members(&self) -> Option<&[GuildMember]> {
         self.members.as_deref()
     }
@@ -832,7 +997,7 @@ impl AvailableGuild {
         self.premium_subscription_count
     }
 
-    pub fn preferred_locale(&self) -> &str {
+    pub fn preferred_locale(&self) -> &Locale {
         &self.preferred_locale
     }
 
@@ -844,10 +1009,19 @@ impl AvailableGuild {
         self.max_video_channel_users
     }
 
+    pub fn max_stage_video_channel_users(&self) -> Option<u64> {
+        self.max_stage_video_channel_users
+    }
+
     pub fn approximate_member_count(&self) -> Option<u64> {
         self.approximate_member_count
     }
 
+    /// Only present when fetched with `with_counts`.
+    pub fn approximate_presence_count(&self) -> Option<u64> {
+        self.approximate_presence_count
+    }
+
     pub fn welcome_screen(&self) -> Option<&WelcomeScreen> {
         self.welcome_screen.as_ref()
     }
@@ -855,29 +1029,310 @@ impl AvailableGuild {
     pub fn nsfw(&self) -> Option<bool> {
         self.nsfw
     }
+
+    pub fn try_nsfw_level(
+        &self,
+    ) -> Option<Result<NsfwLevel, EnumFromIntegerError>> {
+        self.nsfw_level.map(IntegerEnum::try_unwrap)
+    }
+
+    pub fn nsfw_level(&self) -> Option<NsfwLevel> {
+        self.nsfw_level.map(IntegerEnum::unwrap)
+    }
+
+    pub fn stickers(&self) -> Option<&[Sticker]> {
+        self.stickers.as_deref()
+    }
+
+    /// Whether the boost progress bar is shown.
+    pub fn premium_progress_bar_enabled(&self) -> Option<bool> {
+        self.premium_progress_bar_enabled
+    }
+}
+
+/// The public view of a discoverable guild, available without joining it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildPreview {
+    id: GuildId,
+    name: String,
+    icon: Option<String>,
+    splash: Option<String>,
+    discovery_splash: Option<String>,
+    emojis: Vec<Emoji>,
+    features: Vec<StringEnum<GuildFeature>>,
+    approximate_member_count: u64,
+    approximate_presence_count: u64,
+    description: Option<String>,
+}
+
+impl GuildPreview {
+    pub fn id(&self) -> GuildId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn icon(&self) -> Option<GuildIcon> {
+        self.icon.as_deref().map(|b| GuildIcon::new(self.id, b))
+    }
+
+    pub fn splash(&self) -> Option<GuildSplash> {
+        self.splash.as_deref().map(|b| GuildSplash::new(self.id, b))
+    }
+
+    pub fn discovery_splash(&self) -> Option<GuildDiscoverySplash> {
+        self.discovery_splash
+            .as_deref()
+            .map(|b| GuildDiscoverySplash::new(self.id, b))
+    }
+
+    pub fn emojis(&self) -> &[Emoji] {
+        &self.emojis
+    }
+
+    pub fn try_features(
+        &self,
+    ) -> impl Iterator<Item = &StringEnum<GuildFeature>> {
+        self.features.iter()
+    }
+
+    pub fn features(&self) -> impl Iterator<Item = GuildFeature> + '_ {
+        self.features.iter().map(|x| x.unwrap())
+    }
+
+    pub fn approximate_member_count(&self) -> u64 {
+        self.approximate_member_count
+    }
+
+    pub fn approximate_presence_count(&self) -> u64 {
+        self.approximate_presence_count
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GuildMember {
     user: Option<User>,
     nick: Option<String>,
+    avatar: Option<String>,
     roles: Vec<RoleId>,
     joined_at: DateTime<FixedOffset>,
     premium_since: Option<DateTime<FixedOffset>>,
     deaf: bool,
     mute: bool,
     pending: Option<bool>,
-    permissions: Option<String>,
+    permissions: Option<StringEnum<Permissions>>,
+    communication_disabled_until: Option<DateTime<FixedOffset>>,
+}
+
+impl GuildMember {
+    pub fn user(&self) -> Option<&User> {
+        self.user.as_ref()
+    }
+
+    pub fn nick(&self) -> Option<&str> {
+        self.nick.as_deref()
+    }
+
+    /// The member's avatar in the guild with id `guild_id`, which must be the
+    /// guild this member was retrieved from.
+    ///
+    /// Returns `None` if the member has no guild avatar, or if the member
+    /// object didn't include its user.
+    pub fn guild_avatar(&self, guild_id: GuildId) -> Option<GuildMemberAvatar> {
+        let user_id = self.user.as_ref()?.id();
+        self.avatar
+            .as_deref()
+            .map(|h| GuildMemberAvatar::new(guild_id, user_id, h))
+    }
+
+    pub fn roles(&self) -> &[RoleId] {
+        &self.roles
+    }
+
+    pub fn joined_at(&self) -> DateTime<FixedOffset> {
+        self.joined_at
+    }
+
+    pub fn premium_since(&self) -> Option<DateTime<FixedOffset>> {
+        self.premium_since
+    }
+
+    pub fn deaf(&self) -> bool {
+        self.deaf
+    }
+
+    pub fn mute(&self) -> bool {
+        self.mute
+    }
+
+    pub fn pending(&self) -> Option<bool> {
+        self.pending
+    }
+
+    pub fn try_permissions(
+        &self,
+    ) -> Option<Result<Permissions, ParseEnumError>> {
+        self.permissions.as_ref().map(StringEnum::try_unwrap)
+    }
+
+    pub fn permissions(&self) -> Option<Permissions> {
+        self.permissions.as_ref().map(StringEnum::unwrap)
+    }
+
+    /// When the member's timeout expires. A timestamp in the past means the
+    /// member is no longer timed out.
+    pub fn communication_disabled_until(
+        &self,
+    ) -> Option<DateTime<FixedOffset>> {
+        self.communication_disabled_until
+    }
+}
+
+#[derive(Educe, Clone, Serialize)]
+#[educe(Debug)]
+pub(crate) struct NewGuildMember {
+    #[educe(Debug(method = "obscure"))]
+    pub(crate) access_token: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) nick: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) roles: Option<Vec<RoleId>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) mute: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) deaf: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct EditGuildMember {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) nick: Option<Option<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) roles: Option<Vec<RoleId>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) mute: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) deaf: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) channel_id: Option<Option<ChannelId>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) communication_disabled_until:
+        Option<Option<DateTime<FixedOffset>>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct EditCurrentMember {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) nick: Option<Option<String>>,
 }
 
 #[cfg(test)]
 mod tests {
     use crate::image::Image;
+    use crate::round_trip::assert_round_trip;
 
     use super::*;
 
     use serde_json::json;
 
+    #[test]
+    fn deserialize_guild_member() {
+        let json = json!({
+            "user": {
+                "id": "80351110224678912",
+                "username": "Nelly",
+                "discriminator": "1337",
+                "avatar": null
+            },
+            "nick": "NOT API SUPPORT",
+            "roles": ["41771983423143936", "41771983423143937"],
+            "joined_at": "2015-04-26T06:26:56.936000+00:00",
+            "premium_since": null,
+            "deaf": false,
+            "mute": true,
+            "permissions": "8"
+        });
+
+        let member: GuildMember = serde_json::from_value(json).unwrap();
+
+        assert_eq!(member.user().unwrap().username(), "Nelly");
+        assert_eq!(member.nick(), Some("NOT API SUPPORT"));
+        assert_eq!(
+            member.roles(),
+            [41771983423143936.into(), 41771983423143937.into()]
+        );
+        assert_eq!(
+            member.joined_at(),
+            DateTime::parse_from_rfc3339("2015-04-26T06:26:56.936Z").unwrap()
+        );
+        assert!(member.premium_since().is_none());
+        assert!(!member.deaf());
+        assert!(member.mute());
+        assert_eq!(member.pending(), None);
+        assert_eq!(member.permissions(), Some(Permissions::ADMINISTRATOR));
+        assert_eq!(member.communication_disabled_until(), None);
+    }
+
+    #[test]
+    fn deserialize_guild_member_timed_out() {
+        let json = json!({
+            "roles": [],
+            "joined_at": "2015-04-26T06:26:56.936000+00:00",
+            "deaf": false,
+            "mute": false,
+            "communication_disabled_until": "2022-01-09T12:00:00+00:00"
+        });
+
+        let member: GuildMember = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            member.communication_disabled_until(),
+            Some(DateTime::parse_from_rfc3339("2022-01-09T12:00:00Z").unwrap())
+        );
+    }
+
+    #[test]
+    fn guild_member_avatar() {
+        let json = json!({
+            "user": {
+                "id": "80351110224678912",
+                "username": "Nelly",
+                "discriminator": "1337",
+                "avatar": "8342729096ea3675442027381ff50dfe"
+            },
+            "avatar": "a_1269e74af4df7417b13759eae50c83dc",
+            "roles": [],
+            "joined_at": "2015-04-26T06:26:56.936000+00:00",
+            "deaf": false,
+            "mute": false
+        });
+
+        let member: GuildMember = serde_json::from_value(json).unwrap();
+
+        let avatar = member.guild_avatar(197038439483310086.into()).unwrap();
+        assert_eq!(
+            avatar.bare_path(),
+            "guilds/197038439483310086/users/80351110224678912/avatars/\
+             a_1269e74af4df7417b13759eae50c83dc"
+        );
+        assert!(avatar.supports(image::Format::Gif));
+    }
+
     #[test]
     fn deserialize_guild_features() {
         let json = json!(["ANIMATED_ICON", "BANNER", "FLOOP"]);
@@ -891,9 +1346,8 @@ mod tests {
         assert_eq!(features[2].to_string(), "FLOOP");
     }
 
-    #[test]
-    fn deserialize_guild_available() {
-        let json = json!({
+    fn available_guild_json() -> serde_json::Value {
+        json!({
             "id": "197038439483310086",
             "name": "Discord Testers",
             "icon": "f64c482b807da4f539cff778d174971c",
@@ -935,7 +1389,17 @@ mod tests {
             "preferred_locale": "en-US",
             "rules_channel_id": "441688182833020939",
             "public_updates_channel_id": "281283303326089216"
-        });
+        })
+    }
+
+    #[test]
+    fn available_guild_round_trip() {
+        assert_round_trip::<AvailableGuild>(available_guild_json());
+    }
+
+    #[test]
+    fn deserialize_guild_available() {
+        let json = available_guild_json();
 
         let guild: Guild = serde_json::from_value(json).unwrap();
         let avail = guild.into_available().unwrap();
@@ -997,7 +1461,7 @@ mod tests {
         assert_eq!(avail.premium_tier(), PremiumTier::Tier3);
         assert_eq!(avail.premium_subscription_count(), Some(33));
         assert_eq!(avail.system_channel_flags(), SystemChannelFlags::empty());
-        assert_eq!(avail.preferred_locale(), "en-US");
+        assert_eq!(avail.preferred_locale(), &Locale::EnglishUs);
         assert_eq!(avail.rules_channel_id(), Some(441688182833020939.into()));
         assert_eq!(
             avail.public_updates_channel_id(),
@@ -1005,6 +1469,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_guild_stickers_and_presence_count() {
+        let mut json = available_guild_json();
+        json["approximate_presence_count"] = json!(7);
+        json["stickers"] = json!([{
+            "id": "749054660769218631",
+            "name": "Wave",
+            "tags": "wumpus, hello",
+            "type": 2,
+            "format_type": 1,
+            "description": "Wumpus waves hello",
+            "available": true,
+            "guild_id": "197038439483310086"
+        }]);
+
+        let guild: AvailableGuild = serde_json::from_value(json).unwrap();
+
+        assert_eq!(guild.approximate_presence_count(), Some(7));
+
+        let stickers = guild.stickers().unwrap();
+        assert_eq!(stickers.len(), 1);
+        assert_eq!(stickers[0].name(), "Wave");
+        assert_eq!(stickers[0].guild_id(), Some(guild.id()));
+    }
+
+    #[test]
+    fn deserialize_guild_nsfw_level() {
+        let mut json = available_guild_json();
+        json["nsfw_level"] = json!(1);
+        json["premium_progress_bar_enabled"] = json!(true);
+        json["max_stage_video_channel_users"] = json!(50);
+
+        let guild: AvailableGuild = serde_json::from_value(json).unwrap();
+
+        assert_eq!(guild.nsfw_level(), Some(NsfwLevel::Explicit));
+        assert_eq!(guild.premium_progress_bar_enabled(), Some(true));
+        assert_eq!(guild.max_stage_video_channel_users(), Some(50));
+    }
+
+    #[test]
+    fn require_available_guild() {
+        let guild: Guild =
+            serde_json::from_value(available_guild_json()).unwrap();
+
+        assert_eq!(guild.name(), Some("Discord Testers"));
+        assert_eq!(
+            guild.require_available().unwrap().id(),
+            197038439483310086.into()
+        );
+    }
+
+    #[test]
+    fn require_available_unavailable_guild() {
+        let json = json!({
+            "id": "41771983423143937",
+            "unavailable": true
+        });
+
+        let guild: Guild = serde_json::from_value(json).unwrap();
+
+        assert_eq!(guild.name(), None);
+
+        let err = guild.require_available().unwrap_err();
+        assert_eq!(err.id(), 41771983423143937.into());
+        assert_eq!(err.to_string(), "guild 41771983423143937 is unavailable");
+    }
+
     #[test]
     fn deserialize_guild_unavailable() {
         let json = json!({
@@ -1165,4 +1696,150 @@ mod tests {
         assert_eq!(channels[4].emoji_id(), None);
         assert_eq!(channels[4].emoji_name(), Some("\u{1F526}"));
     }
+
+    #[test]
+    fn serialize_edit_welcome_screen() {
+        let edit = EditWelcomeScreen {
+            enabled: None,
+            description: Some("Discord Developers...".to_owned()),
+            welcome_channels: Some(vec![
+                WelcomeScreenChannel::builder()
+                    .channel_id(697138785317814292.into())
+                    .description("Follow for official Discord API updates")
+                    .emoji_name("\u{1F4E1}")
+                    .build(),
+                WelcomeScreenChannel::builder()
+                    .channel_id(697236247739105340.into())
+                    .description("Custom emoji channel")
+                    .emoji_id(41771983429993937.into())
+                    .build(),
+            ]),
+        };
+
+        let json = serde_json::to_value(&edit).unwrap();
+
+        let expected = json!({
+            "description": "Discord Developers...",
+            "welcome_channels": [
+            {
+                "channel_id": "697138785317814292",
+                "description": "Follow for official Discord API updates",
+                "emoji_id": null,
+                "emoji_name": "\u{1F4E1}"
+            },
+            {
+                "channel_id": "697236247739105340",
+                "description": "Custom emoji channel",
+                "emoji_id": "41771983429993937",
+                "emoji_name": null
+            }
+            ]
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn deserialize_guild_unknown_enums() {
+        let json = json!({
+            "id": "197038439483310086",
+            "name": "Discord Testers",
+            "icon": null,
+            "description": null,
+            "splash": null,
+            "discovery_splash": null,
+            "features": [],
+            "emojis": [],
+            "banner": null,
+            "owner_id": "73193882359173120",
+            "application_id": null,
+            "region": "us-west",
+            "afk_channel_id": null,
+            "afk_timeout": 300,
+            "system_channel_id": null,
+            "verification_level": 99,
+            "roles": [],
+            "default_message_notifications": 98,
+            "mfa_level": 97,
+            "explicit_content_filter": 96,
+            "vanity_url_code": null,
+            "premium_tier": 95,
+            "system_channel_flags": 1u64 << 40,
+            "preferred_locale": "en-US",
+            "rules_channel_id": null,
+            "public_updates_channel_id": null
+        });
+
+        let guild: Guild = serde_json::from_value(json).unwrap();
+        let avail = guild.into_available().unwrap();
+
+        assert_eq!(avail.try_verification_level().unwrap_err().inner(), 99);
+        assert_eq!(
+            avail
+                .try_default_message_notifications()
+                .unwrap_err()
+                .inner(),
+            98
+        );
+        assert_eq!(avail.try_mfa_level().unwrap_err().inner(), 97);
+        assert_eq!(
+            avail.try_explicit_content_filter().unwrap_err().inner(),
+            96
+        );
+        assert_eq!(avail.try_premium_tier().unwrap_err().inner(), 95);
+        assert_eq!(
+            avail.try_system_channel_flags().unwrap_err().inner(),
+            1 << 40
+        );
+    }
+
+    #[test]
+    fn deserialize_guild_preview() {
+        let json = json!({
+            "id": "197038439483310086",
+            "name": "Discord Testers",
+            "icon": "f64c482b807da4f539cff778d174971c",
+            "splash": null,
+            "discovery_splash": null,
+            "emojis": [],
+            "features": [
+                "DISCOVERABLE",
+                "VANITY_URL",
+                "ANIMATED_ICON",
+                "INVITE_SPLASH",
+                "NEWS",
+                "COMMUNITY",
+                "BANNER",
+                "VERIFIED",
+                "MORE_EMOJI"
+            ],
+            "approximate_member_count": 60814,
+            "approximate_presence_count": 20034,
+            "description": "The official place to report Discord Bugs!"
+        });
+
+        let preview: GuildPreview = serde_json::from_value(json).unwrap();
+
+        assert_eq!(preview.id(), 197038439483310086.into());
+        assert_eq!(preview.name(), "Discord Testers");
+        assert_eq!(
+            preview.icon().unwrap().bare_path(),
+            "icons/197038439483310086/f64c482b807da4f539cff778d174971c"
+        );
+        assert!(preview.splash().is_none());
+        assert!(preview.discovery_splash().is_none());
+        assert!(preview.emojis().is_empty());
+        assert_eq!(preview.approximate_member_count(), 60814);
+        assert_eq!(preview.approximate_presence_count(), 20034);
+        assert_eq!(
+            preview.description(),
+            Some("The official place to report Discord Bugs!")
+        );
+
+        let features: Vec<_> = preview.try_features().collect();
+        assert_eq!(features.len(), 9);
+        assert_eq!(features[0].unwrap(), GuildFeature::Discoverable);
+        assert_eq!(features[7].unwrap(), GuildFeature::Verified);
+        assert_eq!(features[8].to_string(), "MORE_EMOJI");
+    }
 }
@@ -187,3 +187,33 @@ impl IntegrationApplication {
         self.bot.as_ref()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_integration_unknown_expire_behavior() {
+        let json = json!({
+            "id": "33590653072239123",
+            "name": "A Name",
+            "type": "twitch",
+            "enabled": true,
+            "expire_behavior": 2,
+            "expire_grace_period": 7,
+            "account": {
+                "name": "twitchusername",
+                "id": "1234567"
+            },
+            "synced_at": "2021-06-01T12:30:00+00:00"
+        });
+
+        let int: Integration = serde_json::from_value(json).unwrap();
+
+        let err = int.try_expire_behavior().unwrap().unwrap_err();
+        assert_eq!(err.inner(), 2);
+        assert_eq!(int.expire_grace_period(), Some(7));
+    }
+}
@@ -0,0 +1,407 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chrono::{DateTime, FixedOffset};
+
+use crate::enums::{EnumFromIntegerError, IntegerEnum};
+use crate::resources::channel::ChannelId;
+use crate::resources::guild::{GuildId, GuildMember};
+use crate::resources::user::{User, UserId};
+use crate::snowflake::{AnyId, Id};
+
+use serde::{Deserialize, Serialize};
+
+use std::convert::TryFrom;
+
+use typed_builder::TypedBuilder;
+
+pub type GuildScheduledEventId = Id<GuildScheduledEvent>;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ScheduledEventPrivacyLevel {
+    GuildOnly,
+}
+
+impl TryFrom<u64> for ScheduledEventPrivacyLevel {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            2 => Self::GuildOnly,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<ScheduledEventPrivacyLevel> for u64 {
+    fn from(u: ScheduledEventPrivacyLevel) -> Self {
+        match u {
+            ScheduledEventPrivacyLevel::GuildOnly => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ScheduledEventStatus {
+    Scheduled,
+    Active,
+    Completed,
+    Canceled,
+}
+
+impl TryFrom<u64> for ScheduledEventStatus {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            1 => Self::Scheduled,
+            2 => Self::Active,
+            3 => Self::Completed,
+            4 => Self::Canceled,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<ScheduledEventStatus> for u64 {
+    fn from(u: ScheduledEventStatus) -> Self {
+        match u {
+            ScheduledEventStatus::Scheduled => 1,
+            ScheduledEventStatus::Active => 2,
+            ScheduledEventStatus::Completed => 3,
+            ScheduledEventStatus::Canceled => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ScheduledEventEntityKind {
+    StageInstance,
+    Voice,
+    External,
+}
+
+impl TryFrom<u64> for ScheduledEventEntityKind {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            1 => Self::StageInstance,
+            2 => Self::Voice,
+            3 => Self::External,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<ScheduledEventEntityKind> for u64 {
+    fn from(u: ScheduledEventEntityKind) -> Self {
+        match u {
+            ScheduledEventEntityKind::StageInstance => 1,
+            ScheduledEventEntityKind::Voice => 2,
+            ScheduledEventEntityKind::External => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
+pub struct EntityMetadata {
+    #[builder(default, setter(strip_option, into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+}
+
+impl EntityMetadata {
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildScheduledEvent {
+    id: GuildScheduledEventId,
+    guild_id: GuildId,
+    channel_id: Option<ChannelId>,
+    creator_id: Option<UserId>,
+    name: String,
+    description: Option<String>,
+    scheduled_start_time: DateTime<FixedOffset>,
+    scheduled_end_time: Option<DateTime<FixedOffset>>,
+    privacy_level: IntegerEnum<ScheduledEventPrivacyLevel>,
+    status: IntegerEnum<ScheduledEventStatus>,
+    #[serde(rename = "entity_type")]
+    entity_kind: IntegerEnum<ScheduledEventEntityKind>,
+    entity_id: Option<AnyId>,
+    entity_metadata: Option<EntityMetadata>,
+    creator: Option<User>,
+    user_count: Option<u64>,
+    image: Option<String>,
+}
+
+impl GuildScheduledEvent {
+    pub fn id(&self) -> GuildScheduledEventId {
+        self.id
+    }
+
+    pub fn guild_id(&self) -> GuildId {
+        self.guild_id
+    }
+
+    pub fn channel_id(&self) -> Option<ChannelId> {
+        self.channel_id
+    }
+
+    pub fn creator_id(&self) -> Option<UserId> {
+        self.creator_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn scheduled_start_time(&self) -> DateTime<FixedOffset> {
+        self.scheduled_start_time
+    }
+
+    pub fn scheduled_end_time(&self) -> Option<DateTime<FixedOffset>> {
+        self.scheduled_end_time
+    }
+
+    pub fn try_privacy_level(
+        &self,
+    ) -> Result<ScheduledEventPrivacyLevel, EnumFromIntegerError> {
+        self.privacy_level.try_unwrap()
+    }
+
+    pub fn privacy_level(&self) -> ScheduledEventPrivacyLevel {
+        self.privacy_level.unwrap()
+    }
+
+    pub fn try_status(
+        &self,
+    ) -> Result<ScheduledEventStatus, EnumFromIntegerError> {
+        self.status.try_unwrap()
+    }
+
+    pub fn status(&self) -> ScheduledEventStatus {
+        self.status.unwrap()
+    }
+
+    pub fn try_entity_kind(
+        &self,
+    ) -> Result<ScheduledEventEntityKind, EnumFromIntegerError> {
+        self.entity_kind.try_unwrap()
+    }
+
+    pub fn entity_kind(&self) -> ScheduledEventEntityKind {
+        self.entity_kind.unwrap()
+    }
+
+    pub fn entity_id(&self) -> Option<AnyId> {
+        self.entity_id
+    }
+
+    pub fn entity_metadata(&self) -> Option<&EntityMetadata> {
+        self.entity_metadata.as_ref()
+    }
+
+    pub fn creator(&self) -> Option<&User> {
+        self.creator.as_ref()
+    }
+
+    pub fn user_count(&self) -> Option<u64> {
+        self.user_count
+    }
+
+    pub fn image(&self) -> Option<&str> {
+        self.image.as_deref()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledEventUser {
+    guild_scheduled_event_id: GuildScheduledEventId,
+    user: User,
+    member: Option<GuildMember>,
+}
+
+impl ScheduledEventUser {
+    pub fn guild_scheduled_event_id(&self) -> GuildScheduledEventId {
+        self.guild_scheduled_event_id
+    }
+
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+
+    pub fn member(&self) -> Option<&GuildMember> {
+        self.member.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct NewScheduledEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) channel_id: Option<ChannelId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) entity_metadata: Option<EntityMetadata>,
+
+    pub(crate) name: String,
+
+    pub(crate) privacy_level: IntegerEnum<ScheduledEventPrivacyLevel>,
+
+    pub(crate) scheduled_start_time: DateTime<FixedOffset>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) scheduled_end_time: Option<DateTime<FixedOffset>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+
+    #[serde(rename = "entity_type")]
+    pub(crate) entity_kind: IntegerEnum<ScheduledEventEntityKind>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct EditScheduledEvent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) channel_id: Option<Option<ChannelId>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) entity_metadata: Option<EntityMetadata>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) privacy_level: Option<IntegerEnum<ScheduledEventPrivacyLevel>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) scheduled_start_time: Option<DateTime<FixedOffset>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) scheduled_end_time: Option<DateTime<FixedOffset>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "entity_type")]
+    pub(crate) entity_kind: Option<IntegerEnum<ScheduledEventEntityKind>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) status: Option<IntegerEnum<ScheduledEventStatus>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_guild_scheduled_event() {
+        let json = json!({
+            "id": "912353719488069673",
+            "guild_id": "197038439483310086",
+            "channel_id": null,
+            "creator_id": "53908099506183680",
+            "name": "Community Night",
+            "description": "Games and chat",
+            "scheduled_start_time": "2021-12-01T20:00:00+00:00",
+            "scheduled_end_time": "2021-12-01T22:00:00+00:00",
+            "privacy_level": 2,
+            "status": 1,
+            "entity_type": 3,
+            "entity_id": null,
+            "entity_metadata": {
+                "location": "The Park"
+            },
+            "creator": {
+                "id": "53908099506183680",
+                "username": "Mason",
+                "discriminator": "9999",
+                "avatar": null
+            },
+            "user_count": 12,
+            "image": null
+        });
+
+        let event: GuildScheduledEvent = serde_json::from_value(json).unwrap();
+
+        assert_eq!(event.id(), 912353719488069673.into());
+        assert_eq!(event.guild_id(), 197038439483310086.into());
+        assert_eq!(event.channel_id(), None);
+        assert_eq!(event.creator_id(), Some(53908099506183680.into()));
+        assert_eq!(event.name(), "Community Night");
+        assert_eq!(event.description(), Some("Games and chat"));
+        assert_eq!(
+            event.scheduled_start_time(),
+            DateTime::parse_from_rfc3339("2021-12-01T20:00:00+00:00").unwrap()
+        );
+        assert_eq!(
+            event.scheduled_end_time(),
+            Some(
+                DateTime::parse_from_rfc3339("2021-12-01T22:00:00+00:00")
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            event.privacy_level(),
+            ScheduledEventPrivacyLevel::GuildOnly
+        );
+        assert_eq!(event.status(), ScheduledEventStatus::Scheduled);
+        assert_eq!(event.entity_kind(), ScheduledEventEntityKind::External);
+        assert_eq!(event.entity_id(), None);
+        assert_eq!(
+            event.entity_metadata().unwrap().location(),
+            Some("The Park")
+        );
+        assert_eq!(event.creator().unwrap().username(), "Mason");
+        assert_eq!(event.user_count(), Some(12));
+        assert_eq!(event.image(), None);
+    }
+
+    #[test]
+    fn serialize_new_scheduled_event() {
+        let event = NewScheduledEvent {
+            channel_id: None,
+            entity_metadata: Some(
+                EntityMetadata::builder().location("The Park").build(),
+            ),
+            name: "Community Night".into(),
+            privacy_level: ScheduledEventPrivacyLevel::GuildOnly.into(),
+            scheduled_start_time: DateTime::parse_from_rfc3339(
+                "2021-12-01T20:00:00+00:00",
+            )
+            .unwrap(),
+            scheduled_end_time: None,
+            description: None,
+            entity_kind: ScheduledEventEntityKind::External.into(),
+        };
+
+        let json = serde_json::to_value(&event).unwrap();
+
+        let expected = json!({
+            "entity_metadata": {"location": "The Park"},
+            "name": "Community Night",
+            "privacy_level": 2,
+            "scheduled_start_time": "2021-12-01T20:00:00Z",
+            "entity_type": 3
+        });
+
+        assert_eq!(json, expected);
+    }
+}
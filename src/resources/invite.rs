@@ -1,3 +1,223 @@
 // This Source Code Form is subject to the terms of the Mozilla Public
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chrono::{DateTime, FixedOffset};
+
+use crate::enums::{EnumFromIntegerError, IntegerEnum};
+use crate::resources::channel::Channel;
+use crate::resources::guild::GuildId;
+use crate::resources::guild_scheduled_event::GuildScheduledEvent;
+use crate::resources::user::User;
+
+use serde::{Deserialize, Serialize};
+
+use std::convert::TryFrom;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum InviteTargetKind {
+    Stream,
+    EmbeddedApplication,
+}
+
+impl TryFrom<u64> for InviteTargetKind {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            1 => Self::Stream,
+            2 => Self::EmbeddedApplication,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<InviteTargetKind> for u64 {
+    fn from(u: InviteTargetKind) -> Self {
+        match u {
+            InviteTargetKind::Stream => 1,
+            InviteTargetKind::EmbeddedApplication => 2,
+        }
+    }
+}
+
+/// The partial guild included with an [`Invite`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InviteGuild {
+    id: GuildId,
+    name: String,
+    icon: Option<String>,
+    splash: Option<String>,
+    banner: Option<String>,
+    description: Option<String>,
+    vanity_url_code: Option<String>,
+}
+
+impl InviteGuild {
+    pub fn id(&self) -> GuildId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn icon(&self) -> Option<&str> {
+        self.icon.as_deref()
+    }
+
+    pub fn splash(&self) -> Option<&str> {
+        self.splash.as_deref()
+    }
+
+    pub fn banner(&self) -> Option<&str> {
+        self.banner.as_deref()
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn vanity_url_code(&self) -> Option<&str> {
+        self.vanity_url_code.as_deref()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Invite {
+    code: String,
+    guild: Option<InviteGuild>,
+    channel: Option<Channel>,
+    inviter: Option<User>,
+    #[serde(rename = "target_type")]
+    target_kind: Option<IntegerEnum<InviteTargetKind>>,
+    target_user: Option<User>,
+    approximate_presence_count: Option<u64>,
+    approximate_member_count: Option<u64>,
+    expires_at: Option<DateTime<FixedOffset>>,
+    guild_scheduled_event: Option<GuildScheduledEvent>,
+}
+
+impl Invite {
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn guild(&self) -> Option<&InviteGuild> {
+        self.guild.as_ref()
+    }
+
+    pub fn channel(&self) -> Option<&Channel> {
+        self.channel.as_ref()
+    }
+
+    pub fn inviter(&self) -> Option<&User> {
+        self.inviter.as_ref()
+    }
+
+    pub fn try_target_kind(
+        &self,
+    ) -> Option<Result<InviteTargetKind, EnumFromIntegerError>> {
+        self.target_kind.map(IntegerEnum::try_unwrap)
+    }
+
+    pub fn target_kind(&self) -> Option<InviteTargetKind> {
+        self.target_kind.map(IntegerEnum::unwrap)
+    }
+
+    pub fn target_user(&self) -> Option<&User> {
+        self.target_user.as_ref()
+    }
+
+    /// Only present when requested with `with_counts`.
+    pub fn approximate_presence_count(&self) -> Option<u64> {
+        self.approximate_presence_count
+    }
+
+    /// Only present when requested with `with_counts`.
+    pub fn approximate_member_count(&self) -> Option<u64> {
+        self.approximate_member_count
+    }
+
+    /// Only present when requested with `with_expiration`. `None` for
+    /// invites that never expire.
+    pub fn expires_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.expires_at
+    }
+
+    /// Only present when requested with `guild_scheduled_event_id`.
+    pub fn guild_scheduled_event(&self) -> Option<&GuildScheduledEvent> {
+        self.guild_scheduled_event.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_invite_with_counts() {
+        let json = json!({
+            "code": "0vCdhLbwjZZTWZLD",
+            "guild": {
+                "id": "165176875973476352",
+                "name": "CS:GO Fraggers Only",
+                "splash": null,
+                "banner": null,
+                "description": "Very good description",
+                "icon": null,
+                "features": ["NEWS", "DISCOVERABLE"],
+                "verification_level": 2,
+                "vanity_url_code": null
+            },
+            "channel": {
+                "id": "165176875973476352",
+                "name": "illuminati",
+                "type": 0
+            },
+            "inviter": {
+                "id": "115590097100865541",
+                "username": "speed",
+                "avatar": "deadbeef",
+                "discriminator": "7653",
+                "public_flags": 131328
+            },
+            "target_type": 1,
+            "target_user": {
+                "id": "165176875973476352",
+                "username": "bob",
+                "avatar": "deadbeef",
+                "discriminator": "1234",
+                "public_flags": 64
+            },
+            "approximate_member_count": 15,
+            "approximate_presence_count": 3,
+            "expires_at": "2021-06-08T20:30:00+00:00"
+        });
+
+        let invite: Invite = serde_json::from_value(json).unwrap();
+
+        assert_eq!(invite.code(), "0vCdhLbwjZZTWZLD");
+
+        let guild = invite.guild().unwrap();
+        assert_eq!(guild.id(), 165176875973476352.into());
+        assert_eq!(guild.name(), "CS:GO Fraggers Only");
+        assert_eq!(guild.description(), Some("Very good description"));
+
+        assert_eq!(invite.channel().unwrap().name(), Some("illuminati"));
+        assert_eq!(invite.inviter().unwrap().username(), "speed");
+        assert_eq!(invite.target_kind(), Some(InviteTargetKind::Stream));
+        assert_eq!(invite.target_user().unwrap().username(), "bob");
+        assert_eq!(invite.approximate_member_count(), Some(15));
+        assert_eq!(invite.approximate_presence_count(), Some(3));
+        assert_eq!(
+            invite.expires_at(),
+            Some(DateTime::parse_from_rfc3339("2021-06-08T20:30:00Z").unwrap())
+        );
+        assert!(invite.guild_scheduled_event().is_none());
+    }
+}
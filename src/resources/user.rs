@@ -4,14 +4,16 @@
 
 use bitflags::bitflags;
 
-use crate::enums::{EnumFromIntegerError, IntegerEnum};
+use crate::enums::{EnumFromIntegerError, IntegerEnum, ParseEnumError};
 use crate::image;
+use crate::locale::Locale;
 use crate::resources::application::ApplicationId;
 use crate::snowflake::Id;
 
 use serde::{Deserialize, Serialize};
 
 use std::convert::TryFrom;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 enum AvatarKind {
@@ -61,6 +63,36 @@ impl image::Image for UserAvatar {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct UserBanner {
+    has_gif: bool,
+    bare_path: String,
+}
+
+impl UserBanner {
+    fn new(uid: UserId, hash: &str) -> Self {
+        Self {
+            has_gif: hash.starts_with("a_"),
+            bare_path: format!("banners/{}/{}", uid, hash),
+        }
+    }
+}
+
+impl image::Image for UserBanner {
+    fn supports(&self, format: image::Format) -> bool {
+        match format {
+            image::Format::Jpeg | image::Format::Png | image::Format::WebP => {
+                true
+            }
+            image::Format::Gif => self.has_gif,
+        }
+    }
+
+    fn bare_path(&self) -> &str {
+        &self.bare_path
+    }
+}
+
 #[derive(Debug)]
 #[doc(hidden)]
 pub struct Bot {
@@ -129,11 +161,14 @@ pub struct User {
     id: UserId,
     username: String,
     discriminator: String,
+    global_name: Option<String>,
     avatar: Option<String>,
+    banner: Option<String>,
+    accent_color: Option<u32>,
     bot: Option<bool>,
     system: Option<bool>,
     mfa_enabled: Option<bool>,
-    locale: Option<String>,
+    locale: Option<Locale>,
     verified: Option<bool>,
     email: Option<String>,
     flags: Option<IntegerEnum<UserFlags>>,
@@ -187,6 +222,16 @@ impl User {
         &self.discriminator
     }
 
+    pub fn global_name(&self) -> Option<&str> {
+        self.global_name.as_deref()
+    }
+
+    /// The name to show for this user: the global name if set, otherwise
+    /// the username.
+    pub fn display_name(&self) -> &str {
+        self.global_name().unwrap_or(&self.username)
+    }
+
     pub fn avatar(&self) -> Option<UserAvatar> {
         self.avatar
             .as_deref()
@@ -199,6 +244,15 @@ impl User {
         })
     }
 
+    pub fn banner(&self) -> Option<UserBanner> {
+        self.banner.as_deref().map(|b| UserBanner::new(self.id, b))
+    }
+
+    /// The banner color, as an RGB integer.
+    pub fn accent_color(&self) -> Option<u32> {
+        self.accent_color
+    }
+
     pub fn bot(&self) -> Option<bool> {
         self.bot
     }
@@ -207,12 +261,20 @@ impl User {
         self.system
     }
 
+    pub fn is_bot(&self) -> bool {
+        self.bot.unwrap_or(false)
+    }
+
+    pub fn is_system(&self) -> bool {
+        self.system.unwrap_or(false)
+    }
+
     pub fn mfa_enabled(&self) -> Option<bool> {
         self.mfa_enabled
     }
 
-    pub fn locale(&self) -> Option<&str> {
-        self.locale.as_deref()
+    pub fn locale(&self) -> Option<&Locale> {
+        self.locale.as_ref()
     }
 
     pub fn verified(&self) -> Option<bool> {
@@ -251,3 +313,310 @@ impl User {
         self.public_flags.map(IntegerEnum::unwrap)
     }
 }
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ConnectionKind {
+    BattleNet,
+    Ebay,
+    EpicGames,
+    Facebook,
+    GitHub,
+    Instagram,
+    LeagueOfLegends,
+    PayPal,
+    PlayStation,
+    Reddit,
+    RiotGames,
+    Skype,
+    Spotify,
+    Steam,
+    TikTok,
+    Twitch,
+    Twitter,
+    Xbox,
+    YouTube,
+}
+
+impl FromStr for ConnectionKind {
+    type Err = ParseEnumError;
+
+    fn from_str(txt: &str) -> Result<Self, Self::Err> {
+        let r = match txt {
+            "battlenet" => Self::BattleNet,
+            "ebay" => Self::Ebay,
+            "epicgames" => Self::EpicGames,
+            "facebook" => Self::Facebook,
+            "github" => Self::GitHub,
+            "instagram" => Self::Instagram,
+            "leagueoflegends" => Self::LeagueOfLegends,
+            "paypal" => Self::PayPal,
+            "playstation" => Self::PlayStation,
+            "reddit" => Self::Reddit,
+            "riotgames" => Self::RiotGames,
+            "skype" => Self::Skype,
+            "spotify" => Self::Spotify,
+            "steam" => Self::Steam,
+            "tiktok" => Self::TikTok,
+            "twitch" => Self::Twitch,
+            "twitter" => Self::Twitter,
+            "xbox" => Self::Xbox,
+            "youtube" => Self::YouTube,
+
+            other => return Err(ParseEnumError::new(other.to_owned())),
+        };
+
+        Ok(r)
+    }
+}
+
+impl AsRef<str> for ConnectionKind {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::BattleNet => "battlenet",
+            Self::Ebay => "ebay",
+            Self::EpicGames => "epicgames",
+            Self::Facebook => "facebook",
+            Self::GitHub => "github",
+            Self::Instagram => "instagram",
+            Self::LeagueOfLegends => "leagueoflegends",
+            Self::PayPal => "paypal",
+            Self::PlayStation => "playstation",
+            Self::Reddit => "reddit",
+            Self::RiotGames => "riotgames",
+            Self::Skype => "skype",
+            Self::Spotify => "spotify",
+            Self::Steam => "steam",
+            Self::TikTok => "tiktok",
+            Self::Twitch => "twitch",
+            Self::Twitter => "twitter",
+            Self::Xbox => "xbox",
+            Self::YouTube => "youtube",
+        }
+    }
+}
+
+impl std::fmt::Display for ConnectionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let txt = self.as_ref();
+        f.write_str(txt)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ConnectionVisibility {
+    /// Only visible to the user.
+    None,
+    Everyone,
+}
+
+impl TryFrom<u64> for ConnectionVisibility {
+    type Error = EnumFromIntegerError;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        let r = match u {
+            0 => Self::None,
+            1 => Self::Everyone,
+            other => return Err(EnumFromIntegerError::new(other)),
+        };
+
+        Ok(r)
+    }
+}
+
+impl From<ConnectionVisibility> for u64 {
+    fn from(u: ConnectionVisibility) -> Self {
+        match u {
+            ConnectionVisibility::None => 0,
+            ConnectionVisibility::Everyone => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Connection {
+    id: String,
+    name: String,
+    #[serde(rename = "type")]
+    kind: String,
+    revoked: Option<bool>,
+    verified: bool,
+    friend_sync: bool,
+    show_activity: bool,
+    visibility: IntegerEnum<ConnectionVisibility>,
+}
+
+impl Connection {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    /// The service as a [`ConnectionKind`], or `None` if this crate doesn't
+    /// know it yet.
+    pub fn known_kind(&self) -> Option<ConnectionKind> {
+        self.kind.parse().ok()
+    }
+
+    pub fn revoked(&self) -> Option<bool> {
+        self.revoked
+    }
+
+    pub fn verified(&self) -> bool {
+        self.verified
+    }
+
+    pub fn friend_sync(&self) -> bool {
+        self.friend_sync
+    }
+
+    pub fn show_activity(&self) -> bool {
+        self.show_activity
+    }
+
+    pub fn try_visibility(
+        &self,
+    ) -> Result<ConnectionVisibility, EnumFromIntegerError> {
+        self.visibility.try_unwrap()
+    }
+
+    pub fn visibility(&self) -> ConnectionVisibility {
+        self.visibility.unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::image::Image;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_connection() {
+        let json = json!({
+            "id": "12345678",
+            "name": "some_streamer",
+            "type": "twitch",
+            "verified": true,
+            "friend_sync": false,
+            "show_activity": true,
+            "visibility": 1
+        });
+
+        let conn: Connection = serde_json::from_value(json).unwrap();
+
+        assert_eq!(conn.id(), "12345678");
+        assert_eq!(conn.name(), "some_streamer");
+        assert_eq!(conn.kind(), "twitch");
+        assert_eq!(conn.known_kind(), Some(ConnectionKind::Twitch));
+        assert_eq!(conn.revoked(), None);
+        assert!(conn.verified());
+        assert!(!conn.friend_sync());
+        assert!(conn.show_activity());
+        assert_eq!(conn.visibility(), ConnectionVisibility::Everyone);
+    }
+
+    #[test]
+    fn deserialize_connection_unknown() {
+        let json = json!({
+            "id": "12345678",
+            "name": "someone",
+            "type": "myspace",
+            "verified": false,
+            "friend_sync": false,
+            "show_activity": false,
+            "visibility": 7
+        });
+
+        let conn: Connection = serde_json::from_value(json).unwrap();
+
+        assert_eq!(conn.kind(), "myspace");
+        assert_eq!(conn.known_kind(), None);
+        assert_eq!(conn.try_visibility().unwrap_err().inner(), 7);
+    }
+
+    #[test]
+    fn deserialize_user_banner() {
+        let json = json!({
+            "id": "80351110224678912",
+            "username": "Nelly",
+            "discriminator": "1337",
+            "avatar": "8342729096ea3675442027381ff50dfe",
+            "banner": "a_06c16474723fe537c283b8efa61a30c8",
+            "accent_color": 16711680
+        });
+
+        let user: User = serde_json::from_value(json).unwrap();
+
+        let banner = user.banner().unwrap();
+        assert_eq!(
+            banner.bare_path(),
+            "banners/80351110224678912/a_06c16474723fe537c283b8efa61a30c8"
+        );
+        assert!(banner.supports(image::Format::Gif));
+        assert_eq!(user.accent_color(), Some(0xff0000));
+    }
+
+    #[test]
+    fn deserialize_user_global_name() {
+        let json = json!({
+            "id": "80351110224678912",
+            "username": "nelly",
+            "discriminator": "0",
+            "global_name": "Nelly",
+            "avatar": null,
+            "bot": true
+        });
+
+        let user: User = serde_json::from_value(json).unwrap();
+
+        assert_eq!(user.global_name(), Some("Nelly"));
+        assert_eq!(user.display_name(), "Nelly");
+        assert!(user.is_bot());
+        assert!(!user.is_system());
+    }
+
+    #[test]
+    fn display_name_falls_back_to_username() {
+        let json = json!({
+            "id": "80351110224678912",
+            "username": "Nelly",
+            "discriminator": "1337",
+            "avatar": null
+        });
+
+        let user: User = serde_json::from_value(json).unwrap();
+
+        assert_eq!(user.global_name(), None);
+        assert_eq!(user.display_name(), "Nelly");
+        assert!(!user.is_bot());
+    }
+
+    #[test]
+    fn deserialize_user_unknown_enums() {
+        let json = json!({
+            "id": "53908099506183680",
+            "username": "Mason",
+            "discriminator": "9999",
+            "avatar": null,
+            "premium_type": 99,
+            "public_flags": 1u64 << 40
+        });
+
+        let user: User = serde_json::from_value(json).unwrap();
+
+        assert_eq!(user.try_premium_kind().unwrap().unwrap_err().inner(), 99);
+        assert_eq!(
+            user.try_public_flags().unwrap().unwrap_err().inner(),
+            1 << 40
+        );
+    }
+}
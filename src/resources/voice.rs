@@ -10,6 +10,19 @@ use crate::resources::user::UserId;
 
 use serde::{Deserialize, Serialize};
 
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct EditVoiceState {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) channel_id: Option<ChannelId>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) suppress: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) request_to_speak_timestamp:
+        Option<Option<DateTime<FixedOffset>>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoiceState {
     guild_id: Option<GuildId>,
@@ -79,6 +92,42 @@ impl VoiceState {
     pub fn request_to_speak_timestamp(&self) -> Option<DateTime<FixedOffset>> {
         self.request_to_speak_timestamp
     }
+
+    /// Whether the user has raised their hand in a stage channel.
+    pub fn is_requesting_to_speak(&self) -> bool {
+        self.request_to_speak_timestamp.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceRegion {
+    id: String,
+    name: String,
+    optimal: bool,
+    deprecated: bool,
+    custom: bool,
+}
+
+impl VoiceRegion {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn optimal(&self) -> bool {
+        self.optimal
+    }
+
+    pub fn deprecated(&self) -> bool {
+        self.deprecated
+    }
+
+    pub fn custom(&self) -> bool {
+        self.custom
+    }
 }
 
 #[cfg(test)]
@@ -117,4 +166,118 @@ mod tests {
         let ts = Utc.ymd(2021, 03, 31).and_hms_micro(18, 45, 31, 297561);
         assert_eq!(voice.request_to_speak_timestamp().unwrap(), ts);
     }
+
+    #[test]
+    fn deserialize_voice_regions() {
+        let json = json!([
+            {
+                "id": "us-west",
+                "name": "US West",
+                "optimal": true,
+                "deprecated": false,
+                "custom": false
+            },
+            {
+                "id": "amsterdam",
+                "name": "Amsterdam",
+                "optimal": false,
+                "deprecated": true,
+                "custom": false
+            }
+        ]);
+
+        let regions: Vec<VoiceRegion> = serde_json::from_value(json).unwrap();
+
+        assert_eq!(regions.len(), 2);
+
+        assert_eq!(regions[0].id(), "us-west");
+        assert_eq!(regions[0].name(), "US West");
+        assert!(regions[0].optimal());
+        assert!(!regions[0].deprecated());
+        assert!(!regions[0].custom());
+
+        assert_eq!(regions[1].id(), "amsterdam");
+        assert_eq!(regions[1].name(), "Amsterdam");
+        assert!(!regions[1].optimal());
+        assert!(regions[1].deprecated());
+        assert!(!regions[1].custom());
+    }
+
+    #[test]
+    fn serialize_edit_voice_state_set_request_to_speak() {
+        let ts =
+            DateTime::parse_from_rfc3339("2021-03-31T18:45:31.297561+00:00")
+                .unwrap();
+
+        let edit = EditVoiceState {
+            channel_id: Some(157733188964188161.into()),
+            suppress: None,
+            request_to_speak_timestamp: Some(Some(ts)),
+        };
+
+        let json = serde_json::to_value(&edit).unwrap();
+
+        let expected = json!({
+            "channel_id": "157733188964188161",
+            "request_to_speak_timestamp": "2021-03-31T18:45:31.297561Z"
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn serialize_edit_voice_state_clear_request_to_speak() {
+        let edit = EditVoiceState {
+            channel_id: Some(157733188964188161.into()),
+            suppress: Some(true),
+            request_to_speak_timestamp: Some(None),
+        };
+
+        let json = serde_json::to_value(&edit).unwrap();
+
+        let expected = json!({
+            "channel_id": "157733188964188161",
+            "suppress": true,
+            "request_to_speak_timestamp": null
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn deserialize_stage_request_to_speak() {
+        let json = json!({
+            "guild_id": "41771983423143937",
+            "channel_id": "157733188964188161",
+            "user_id": "80351110224678912",
+            "session_id": "90326bd25d71d39b9ef95b299e3872ff",
+            "deaf": false,
+            "mute": false,
+            "self_deaf": false,
+            "self_mute": true,
+            "self_stream": false,
+            "self_video": false,
+            "suppress": true,
+            "request_to_speak_timestamp": "2021-03-31T18:45:31.297561+00:00"
+        });
+
+        let state: VoiceState = serde_json::from_value(json).unwrap();
+
+        assert_eq!(state.user_id(), 80351110224678912.into());
+        assert_eq!(state.channel_id(), Some(157733188964188161.into()));
+        assert_eq!(state.session_id(), "90326bd25d71d39b9ef95b299e3872ff");
+        assert!(state.self_mute());
+        assert_eq!(state.self_stream(), Some(false));
+        assert!(state.suppress());
+        assert!(state.is_requesting_to_speak());
+        assert_eq!(
+            state.request_to_speak_timestamp(),
+            Some(
+                DateTime::parse_from_rfc3339(
+                    "2021-03-31T18:45:31.297561+00:00"
+                )
+                .unwrap()
+            )
+        );
+    }
 }
@@ -4,10 +4,15 @@
 
 use crate::enums::{EnumFromIntegerError, IntegerEnum};
 use crate::resources::application::ApplicationId;
-use crate::resources::channel::{Channel, ChannelId};
+use crate::resources::channel::{
+    AllowedMentions, Channel, ChannelId, Component, Embed,
+};
 use crate::resources::guild::GuildId;
 use crate::resources::user::User;
 use crate::snowflake::Id;
+use crate::str::obscure_opt;
+
+use educe::Educe;
 
 use serde::{Deserialize, Serialize};
 
@@ -36,7 +41,8 @@ impl SourceGuild {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Educe, Clone, Serialize, Deserialize)]
+#[educe(Debug)]
 pub struct Webhook {
     id: WebhookId,
     #[serde(rename = "type")]
@@ -46,6 +52,7 @@ pub struct Webhook {
     user: Option<User>,
     name: Option<String>,
     avatar: Option<String>,
+    #[educe(Debug(method = "obscure_opt"))]
     token: Option<String>,
     application_id: Option<ApplicationId>,
     source_guild: Option<SourceGuild>,
@@ -139,10 +146,35 @@ impl From<WebhookKind> for u64 {
     }
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct NewWebhookMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) content: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) username: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) avatar_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) tts: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) embeds: Option<Vec<Embed>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) allowed_mentions: Option<AllowedMentions>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) components: Option<Vec<Component>>,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::image::Image;
     use crate::resources::user::UserFlags;
+    use crate::round_trip::assert_round_trip;
 
     use super::*;
 
@@ -200,8 +232,23 @@ mod tests {
     }
 
     #[test]
-    fn deserialize_webhook_channel_follower() {
+    fn webhook_debug_hides_token() {
         let json = json!({
+            "type": 1,
+            "id": "223704706495545344",
+            "channel_id": "199737254929760256",
+            "token": "3d89bb7572e0fb30d8128367b3b1b44f"
+        });
+
+        let wh: Webhook = serde_json::from_value(json).unwrap();
+        let debug = format!("{:?}", wh);
+
+        assert!(!debug.contains("3d89bb7572e0fb30d8128367b3b1b44f"));
+        assert!(debug.contains("223704706495545344"));
+    }
+
+    fn channel_follower_webhook_json() -> serde_json::Value {
+        json!({
             "type": 2,
             "id": "752831914402115456",
             "name": "Guildy name",
@@ -225,7 +272,17 @@ mod tests {
                 "avatar": "b004ec1740a63ca06ae2e14c5cee11f3",
                 "public_flags": 131328
             }
-        });
+        })
+    }
+
+    #[test]
+    fn webhook_round_trip() {
+        assert_round_trip::<Webhook>(channel_follower_webhook_json());
+    }
+
+    #[test]
+    fn deserialize_webhook_channel_follower() {
+        let json = channel_follower_webhook_json();
 
         let wh: Webhook = serde_json::from_value(json).unwrap();
 
@@ -289,4 +346,21 @@ mod tests {
         assert!(wh.guild_id().is_none());
         assert!(wh.channel_id().is_none());
     }
+
+    #[test]
+    fn deserialize_webhook_unknown_kind() {
+        let json = json!({
+            "id": "223704706495545344",
+            "type": 99,
+            "name": "test webhook",
+            "avatar": null,
+            "channel_id": "199737254929760256",
+            "application_id": null
+        });
+
+        let wh: Webhook = serde_json::from_value(json).unwrap();
+
+        assert_eq!(wh.try_kind().unwrap_err().inner(), 99);
+        assert_eq!(wh.id(), 223704706495545344.into());
+    }
 }
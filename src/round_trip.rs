@@ -0,0 +1,94 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Checks that resources survive being deserialized and serialized again,
+//! which caches rely on.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use serde_json::Value;
+
+fn missing_keys(
+    sample: &Value,
+    output: &Value,
+    path: &str,
+    out: &mut Vec<String>,
+) {
+    match (sample, output) {
+        (Value::Object(sample), Value::Object(output)) => {
+            for (key, value) in sample {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match output.get(key) {
+                    Some(o) => missing_keys(value, o, &child, out),
+                    None if value.is_null() => (),
+                    None => out.push(child),
+                }
+            }
+        }
+        (Value::Array(sample), Value::Array(output)) => {
+            for (idx, (s, o)) in sample.iter().zip(output).enumerate() {
+                missing_keys(s, o, &format!("{}.{}", path, idx), out);
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Parse `sample` as a `T`, serialize it, and parse that output again.
+///
+/// Panics if any non-null field in `sample` is dropped on the way out, or if
+/// serializing the re-parsed value doesn't reproduce the same JSON.
+pub(crate) fn assert_round_trip<T>(sample: Value)
+where
+    T: Serialize + DeserializeOwned,
+{
+    let first: T = serde_json::from_value(sample.clone()).unwrap();
+    let first = serde_json::to_value(&first).unwrap();
+
+    let mut missing = Vec::new();
+    missing_keys(&sample, &first, "", &mut missing);
+    assert!(
+        missing.is_empty(),
+        "fields dropped on serialize: {:?}",
+        missing
+    );
+
+    let second: T = serde_json::from_value(first.clone()).unwrap();
+    let second = serde_json::to_value(&second).unwrap();
+
+    assert_eq!(first, second);
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    #[allow(dead_code)]
+    struct Lossy {
+        kept: u64,
+        #[serde(skip_serializing)]
+        dropped: Option<u64>,
+    }
+
+    #[test]
+    fn round_trip_ignores_null_fields() {
+        assert_round_trip::<Lossy>(json!({ "kept": 1, "dropped": null }));
+    }
+
+    #[test]
+    #[should_panic(expected = "dropped")]
+    fn round_trip_detects_dropped_fields() {
+        assert_round_trip::<Lossy>(json!({ "kept": 1, "dropped": 2 }));
+    }
+}
@@ -3,7 +3,7 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use chrono::offset::Utc;
-use chrono::{DateTime, TimeZone};
+use chrono::{DateTime, Duration, TimeZone};
 
 use crate::visitor::StringOrInteger;
 
@@ -42,13 +42,67 @@ where
     fn from_date_time<Tz: TimeZone>(dt: DateTime<Tz>) -> Option<Self> {
         let unix_ms: u64 = dt.timestamp_millis().try_into().ok()?;
         let discord_ms = unix_ms.checked_sub(EPOCH)?;
+
+        if discord_ms >> 42 != 0 {
+            return None;
+        }
+
         Some(Self::from(discord_ms << 22))
     }
 
+    /// The smallest snowflake with the timestamp `dt`, with the worker,
+    /// process, and increment bits all zeroed.
+    ///
+    /// Useful as a `before` cursor to find everything created strictly
+    /// before `dt`.
+    fn min_for_timestamp<Tz: TimeZone>(dt: DateTime<Tz>) -> Option<Self> {
+        Self::from_date_time(dt)
+    }
+
+    /// The largest snowflake with the timestamp `dt`, with the worker,
+    /// process, and increment bits all set.
+    ///
+    /// Useful as an `after` cursor to find everything created strictly after
+    /// `dt`.
+    fn max_for_timestamp<Tz: TimeZone>(dt: DateTime<Tz>) -> Option<Self> {
+        let min: u64 = Self::from_date_time(dt)?.into();
+        Some(Self::from(min | 0x3F_FFFF))
+    }
+
+    /// When this snowflake was created.
+    ///
+    /// Every 64-bit snowflake decodes to a representable timestamp (the
+    /// largest is in 2154), so this never panics. See
+    /// [`checked_timestamp`](Snowflake::checked_timestamp) for the fallible
+    /// conversion.
     fn timestamp(self) -> DateTime<Utc> {
+        self.checked_timestamp()
+            .expect("snowflake timestamp out of range")
+    }
+
+    /// When this snowflake was created, or `None` if the timestamp cannot be
+    /// represented.
+    fn checked_timestamp(self) -> Option<DateTime<Utc>> {
         let raw: u64 = self.into();
-        let timestamp = (raw >> 22) + EPOCH;
-        Utc.timestamp_millis(timestamp.try_into().unwrap())
+        let timestamp = (raw >> 22).checked_add(EPOCH)?;
+        Utc.timestamp_millis_opt(timestamp.try_into().ok()?)
+            .single()
+    }
+
+    /// Whether this snowflake was created after `other`.
+    ///
+    /// Snowflakes created in the same millisecond are ordered by their
+    /// worker, process, and increment.
+    fn is_newer_than(self, other: Self) -> bool {
+        u64::from(self) > u64::from(other)
+    }
+
+    /// How long ago this snowflake was created.
+    ///
+    /// Negative if the snowflake's timestamp is in the future, like one
+    /// built with [`from_date_time`](Snowflake::from_date_time).
+    fn age(self) -> Duration {
+        Utc::now() - self.timestamp()
     }
 
     fn worker_id(self) -> u8 {
@@ -90,6 +144,21 @@ pub struct Id<For> {
     id: u64,
 }
 
+impl<For> Id<For> {
+    /// Create an id, usable in constants like
+    /// `const ADMIN: UserId = UserId::new(80351110224678912);`.
+    pub const fn new(id: u64) -> Self {
+        Self {
+            _p: PhantomData,
+            id,
+        }
+    }
+
+    pub const fn get(self) -> u64 {
+        self.id
+    }
+}
+
 impl<For> From<Id<For>> for u64 {
     fn from(id: Id<For>) -> Self {
         id.id
@@ -162,6 +231,16 @@ pub struct AnyId {
     id: u64,
 }
 
+impl AnyId {
+    pub const fn new(id: u64) -> Self {
+        Self { id }
+    }
+
+    pub const fn get(self) -> u64 {
+        self.id
+    }
+}
+
 impl From<AnyId> for u64 {
     fn from(id: AnyId) -> Self {
         id.id
@@ -301,6 +380,32 @@ mod tests {
         assert_eq!(s.timestamp(), expected);
     }
 
+    #[test]
+    fn checked_timestamp_max() {
+        let s = TestSnowflake(u64::MAX);
+        let expected = DateTime::parse_from_rfc3339("2154-05-15T07:35:11.103Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(s.checked_timestamp(), Some(expected));
+        assert_eq!(s.timestamp(), expected);
+    }
+
+    #[test]
+    fn is_newer_than() {
+        let older = TestSnowflake(EXAMPLE);
+        let newer = TestSnowflake(EXAMPLE + (1 << 22));
+
+        assert!(newer.is_newer_than(older));
+        assert!(!older.is_newer_than(newer));
+        assert!(!older.is_newer_than(older));
+    }
+
+    #[test]
+    fn age() {
+        let s = TestSnowflake(EXAMPLE);
+        assert!(s.age() > Duration::zero());
+    }
+
     #[test]
     fn from_date_time() {
         let expected = Utc.ymd(2016, 4, 30).and_hms_milli(11, 18, 25, 796);
@@ -308,6 +413,52 @@ mod tests {
         assert_eq!(s.timestamp(), expected);
     }
 
+    #[test]
+    fn min_max_for_timestamp() {
+        let dt = DateTime::parse_from_rfc3339("2021-06-01T00:00:00.123Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let min = TestSnowflake::min_for_timestamp(dt).unwrap();
+        let max = TestSnowflake::max_for_timestamp(dt).unwrap();
+
+        assert_eq!(min.timestamp(), dt);
+        assert_eq!(max.timestamp(), dt);
+        assert!(min < max);
+
+        assert_eq!(min.worker_id(), 0);
+        assert_eq!(min.process_id(), 0);
+        assert_eq!(min.increment(), 0);
+
+        assert_eq!(max.worker_id(), 0x1F);
+        assert_eq!(max.process_id(), 0x1F);
+        assert_eq!(max.increment(), 0xFFF);
+    }
+
+    #[test]
+    fn min_for_timestamp_before_epoch() {
+        let dt = DateTime::parse_from_rfc3339("2014-12-31T23:59:59Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(TestSnowflake::min_for_timestamp(dt), None);
+        assert_eq!(TestSnowflake::max_for_timestamp(dt), None);
+    }
+
+    #[test]
+    fn get_and_const_new() {
+        struct Sample;
+        type SampleId = Id<Sample>;
+
+        const SAMPLE: SampleId = SampleId::new(123456799);
+        const ANY: AnyId = AnyId::new(987654321);
+
+        assert_eq!(SAMPLE.get(), 123456799);
+        assert_eq!(SAMPLE, SampleId::from(123456799));
+        assert_eq!(ANY.get(), 987654321);
+        assert_eq!(AnyId::from(SAMPLE).get(), 123456799);
+    }
+
     #[test]
     fn deserialize_string() {
         #[derive(Debug, Serialize, Deserialize)]
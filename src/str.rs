@@ -10,3 +10,13 @@ where
 {
     f.write_str(&"*".repeat(txt.as_ref().len()))
 }
+
+pub fn obscure_opt<T>(txt: &Option<T>, f: &mut fmt::Formatter) -> fmt::Result
+where
+    T: AsRef<str>,
+{
+    match txt {
+        Some(txt) => obscure(txt, f),
+        None => f.write_str("None"),
+    }
+}
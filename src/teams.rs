@@ -13,7 +13,7 @@ use std::convert::TryFrom;
 
 pub type TeamId = Id<Team>;
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum MembershipState {
     Invited,
     Accepted,